@@ -0,0 +1,52 @@
+use rocket_launch_live::api_models::{Launch, Response};
+use rocket_launch_live::{LaunchParamsBuilder, RocketLaunchLive};
+use std::error::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// Canned response body, as the API would return it for a single launch.
+const LAUNCHES: &str = include_str!("../tests/fixtures/launch.json");
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // Listen on a free local port, standing in for the API server.
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_url = format!("http://{}", listener.local_addr()?);
+
+    // Answer a single request with the canned body and hand back its request line.
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await?;
+        let mut buffer = vec![0; 4096];
+        let read = socket.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            LAUNCHES.len(),
+            LAUNCHES
+        );
+        socket.write_all(response.as_bytes()).await?;
+
+        Ok::<_, std::io::Error>(request.lines().next().unwrap_or_default().to_string())
+    });
+
+    // Point the client at the mock server instead of the live API.
+    let client = RocketLaunchLive::with_base_url("test-key", &base_url)?;
+    let params = LaunchParamsBuilder::new()
+        .country_code("US")
+        .limit(1)
+        .build();
+    let resp: Response<Launch> = client.launches(Some(params)).await?;
+
+    // Check the query string sent and the response parsed by the client.
+    let request_line = server.await??;
+    assert_eq!(
+        request_line,
+        "GET /json/launches?country_code=US&limit=1 HTTP/1.1"
+    );
+    assert_eq!(resp.result[0].name, "Test Flight");
+
+    println!("{}", request_line);
+
+    Ok(())
+}
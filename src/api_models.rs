@@ -1,51 +1,251 @@
 /// API model type definitions.
-use serde::Deserialize;
-use serde::Serialize;
+use crate::{CountryCode, RllError};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub struct Response<T> {
     pub errors: Option<Vec<String>>,
+    #[serde(default)]
     pub valid_auth: bool,
     pub count: Option<i64>,
     pub limit: Option<i64>,
     pub total: Option<i64>,
     pub last_page: Option<i64>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub result: Vec<T>,
+    /// Rate limit state sent in the response headers, if any.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimit>,
+    /// Records left out of the result because they could not be parsed, in lenient requests.
+    #[serde(skip)]
+    pub skipped: Vec<Value>,
+}
+
+impl<T> Response<T> {
+    /// Get the envelope fields of the response, without the result.
+    pub fn metadata(&self) -> ResponseMeta {
+        ResponseMeta {
+            valid_auth: self.valid_auth,
+            count: self.count,
+            limit: self.limit,
+            total: self.total,
+            last_page: self.last_page,
+        }
+    }
+
+    /// Split the response into its records and its envelope fields, such as the pagination.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::Response;
+    ///
+    /// let resp = Response {
+    ///     total: Some(1),
+    ///     last_page: Some(1),
+    ///     result: vec!["launch"],
+    ///     ..Default::default()
+    /// };
+    /// let (launches, pagination) = resp.split();
+    ///
+    /// assert_eq!(launches, ["launch"]);
+    /// assert!(!pagination.has_more(1));
+    /// ```
+    pub fn split(self) -> (Vec<T>, ResponseMeta) {
+        let meta = self.metadata();
+
+        (self.result, meta)
+    }
+
+    /// Check if there's a page after the current page.
+    ///
+    /// There's no next page when the total is zero, or when neither last_page nor total and
+    /// limit tell how many pages there are.
+    pub fn has_next_page(&self, current_page: i64) -> bool {
+        self.total != Some(0) && self.metadata().has_more(current_page)
+    }
+
+    /// Get the number of the page after the current page, if there's one.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::Response;
+    ///
+    /// let resp: Response<()> = Response {
+    ///     total: Some(30),
+    ///     last_page: Some(2),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(resp.next_page(1), Some(2));
+    /// assert_eq!(resp.next_page(2), None);
+    /// ```
+    pub fn next_page(&self, current_page: i64) -> Option<i64> {
+        self.has_next_page(current_page).then_some(current_page + 1)
+    }
+}
+
+impl Response<Value> {
+    /// Parse each record of the result on its own, moving the ones that fail into skipped.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::{Response, Tag};
+    ///
+    /// let resp: Response<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"result": [{"id": 1, "text": "Crewed"}, {"id": "one", "text": 2}]}"#,
+    /// )
+    /// .unwrap();
+    /// let resp = resp.into_lenient::<Tag>();
+    ///
+    /// assert_eq!(resp.result.len(), 1);
+    /// assert_eq!(resp.skipped.len(), 1);
+    /// ```
+    pub fn into_lenient<T: DeserializeOwned>(self) -> Response<T> {
+        let mut result = Vec::with_capacity(self.result.len());
+        let mut skipped = self.skipped;
+
+        for value in self.result {
+            match T::deserialize(&value) {
+                Ok(record) => result.push(record),
+                Err(_) => skipped.push(value),
+            }
+        }
+
+        Response {
+            errors: self.errors,
+            valid_auth: self.valid_auth,
+            count: self.count,
+            limit: self.limit,
+            total: self.total,
+            last_page: self.last_page,
+            result,
+            rate_limit: self.rate_limit,
+            skipped,
+        }
+    }
+}
+
+impl<T> IntoIterator for Response<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.result.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Response<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.result.iter()
+    }
+}
+
+impl<T> From<Response<T>> for Vec<T> {
+    fn from(resp: Response<T>) -> Self {
+        resp.result
+    }
+}
+
+/// Envelope fields of a response, such as the pagination state.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct ResponseMeta {
+    pub valid_auth: bool,
+    pub count: Option<i64>,
+    pub limit: Option<i64>,
+    pub total: Option<i64>,
+    pub last_page: Option<i64>,
+}
+
+impl ResponseMeta {
+    /// Get the number of pages, from last_page or else from total and limit.
+    pub fn page_count(&self) -> Option<i64> {
+        self.last_page.or_else(|| match (self.total?, self.limit?) {
+            (total, limit) if limit > 0 => Some((total + limit - 1) / limit),
+            _ => None,
+        })
+    }
+
+    /// Check if there are pages after the current page.
+    pub fn has_more(&self, current_page: i64) -> bool {
+        self.page_count()
+            .is_some_and(|page_count| current_page < page_count)
+    }
+}
+
+/// Rate limit state of the API key, read from the X-RateLimit-* response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub remaining: i64,
+    pub reset_at: DateTime<Utc>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Country {
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub code: String,
 }
 
+impl Country {
+    /// Get the code as a known country code, if it is one.
+    pub fn country_code(&self) -> Option<CountryCode> {
+        CountryCode::new(&self.code)
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Company {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub inactive: bool,
+    #[serde(deserialize_with = "null_as_default")]
     pub country: Country,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Launch {
     pub id: Option<i64>,
     pub cospar_id: Option<String>,
+    /// Machine sortable launch date (a Unix timestamp), see [`Launch::sort_key`].
+    #[serde(deserialize_with = "null_as_default")]
     pub sort_date: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub provider: Provider,
+    #[serde(deserialize_with = "null_as_default")]
     pub vehicle: Vehicle,
+    #[serde(deserialize_with = "null_as_default")]
     pub pad: Pad,
+    #[serde(deserialize_with = "null_as_default")]
     pub missions: Vec<Mission>,
     pub mission_description: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
     pub launch_description: String,
     pub win_open: Value,
     pub t0: Option<String>,
     pub win_close: Value,
+    #[serde(deserialize_with = "null_as_default")]
     pub est_date: EstDate,
+    /// Human readable launch date for display only, not suitable for sorting.
+    #[serde(deserialize_with = "null_as_default")]
     pub date_str: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub tags: Vec<Tag>,
+    #[serde(deserialize_with = "null_as_default")]
     pub slug: String,
     pub weather_summary: Value,
     pub weather_temp: Value,
@@ -53,72 +253,991 @@ pub struct Launch {
     pub weather_wind_mph: Value,
     pub weather_icon: Value,
     pub weather_updated: Value,
+    #[serde(deserialize_with = "null_as_default")]
     pub quicktext: String,
-    pub media: Vec<Medum>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub media: Vec<Media>,
     pub result: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub suborbital: bool,
+    #[serde(deserialize_with = "null_as_default")]
     pub modified: String,
 }
 
+impl Launch {
+    /// Check if two launches refer to the same mission.
+    ///
+    /// The COSPAR ids are compared when both launches have one. Otherwise the mission names (or
+    /// the launch names, when a launch has no missions) are compared, ignoring case, spacing and
+    /// punctuation. A launch without a COSPAR id or any name never matches on names.
+    pub fn same_mission_as(&self, other: &Launch) -> bool {
+        let cospar_ids = (
+            self.cospar_id.as_deref().map(str::trim),
+            other.cospar_id.as_deref().map(str::trim),
+        );
+
+        if let (Some(a), Some(b)) = cospar_ids {
+            if !a.is_empty() && !b.is_empty() {
+                return a.eq_ignore_ascii_case(b);
+            }
+        }
+
+        let names = self.mission_names();
+        let other_names = other.mission_names();
+
+        if names.is_empty() || other_names.is_empty() {
+            return false;
+        }
+
+        other_names.iter().any(|name| names.contains(name))
+    }
+
+    /// Get the canonical ordering value of the launch, parsed from sort_date.
+    ///
+    /// Launches with an unparseable sort_date get the latest possible date, so they sort last.
+    pub fn sort_key(&self) -> DateTime<Utc> {
+        self.launch_datetime().unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Get the launch date parsed from sort_date, or None if it's missing or unparseable.
+    pub fn launch_datetime(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.sort_date)
+    }
+
+    /// Get the T-0 time parsed from t0, or None if it's missing or unparseable.
+    ///
+    /// Both the short `2023-09-01T12:30Z` form used by the API and full RFC 3339 timestamps with
+    /// any offset are accepted.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::Launch;
+    ///
+    /// let launch = Launch {
+    ///     t0: Some(String::from("2023-09-01T14:30+02:00")),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(launch.t0_datetime().unwrap().to_rfc3339(), "2023-09-01T12:30:00+00:00");
+    /// ```
+    pub fn t0_datetime(&self) -> Option<DateTime<Utc>> {
+        self.t0.as_deref().and_then(parse_datetime)
+    }
+
+    /// Get the launch window open time parsed from win_open, or None if it's null or unparseable.
+    pub fn win_open_datetime(&self) -> Option<DateTime<Utc>> {
+        self.win_open.as_str().and_then(parse_datetime)
+    }
+
+    /// Get the launch window close time parsed from win_close, or None if it's null or unparseable.
+    pub fn win_close_datetime(&self) -> Option<DateTime<Utc>> {
+        self.win_close.as_str().and_then(parse_datetime)
+    }
+
+    /// Get the time span of the launch window, if both its open and close times are known.
+    pub fn launch_window_duration(&self) -> Option<Duration> {
+        Some(self.win_close_datetime()? - self.win_open_datetime()?)
+    }
+
+    /// Get the T-0 time in the local timezone of the launch site.
+    ///
+    /// The timezone is looked up from the pad location (site name, US state or country), so this
+    /// returns None when either the T-0 or the timezone of the site is unknown.
+    #[cfg(feature = "timezone")]
+    pub fn t0_at_site(&self) -> Option<DateTime<chrono_tz::Tz>> {
+        let t0 = self.t0_datetime()?;
+        let timezone = crate::timezone::site_timezone(&self.pad.location)?;
+
+        Some(t0.with_timezone(&timezone))
+    }
+
+    /// Get the launch window with its open, close and T-0 times parsed, if any of them is known.
+    pub fn window(&self) -> Option<LaunchWindow> {
+        let window = LaunchWindow {
+            open: self.win_open_datetime(),
+            close: self.win_close_datetime(),
+            t0: self.t0_datetime(),
+        };
+
+        if window == LaunchWindow::default() {
+            None
+        } else {
+            Some(window)
+        }
+    }
+
+    /// Get the outcome of the launch decoded from result, or None if there's no result yet.
+    pub fn result_kind(&self) -> Option<LaunchResult> {
+        self.result.map(LaunchResult::from)
+    }
+
+    /// Get the weather forecast with its fields typed, if any of them is known.
+    ///
+    /// Null, empty and unparseable raw values are all treated as unknown.
+    pub fn weather(&self) -> Option<Weather> {
+        let weather = Weather {
+            summary: value_string(&self.weather_summary),
+            temp_f: value_f64(&self.weather_temp),
+            condition: value_string(&self.weather_condition),
+            wind_mph: value_f64(&self.weather_wind_mph),
+            icon: value_string(&self.weather_icon),
+            updated: self.weather_updated_at(),
+        };
+
+        if weather == Weather::default() {
+            None
+        } else {
+            Some(weather)
+        }
+    }
+
+    /// Get the time the weather forecast was last updated, or None if it's null or unparseable.
+    ///
+    /// Both Unix timestamps and API timestamps are accepted.
+    pub fn weather_updated_at(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&value_string(&self.weather_updated)?)
+    }
+
+    /// Get the parts of the quicktext, or None if it's blank.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::Launch;
+    ///
+    /// let launch = Launch {
+    ///     quicktext: String::from(
+    ///         "Falcon 9 - Crew-7 - Sat Aug 26, 2023 07:27:27 UTC \
+    ///          - https://rlaunch.me/crew7 for info/stream #spacex",
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    /// let quicktext = launch.parse_quicktext().unwrap();
+    ///
+    /// assert_eq!(quicktext.vehicle.as_deref(), Some("Falcon 9"));
+    /// assert_eq!(quicktext.mission.as_deref(), Some("Crew-7"));
+    /// assert_eq!(quicktext.date.as_deref(), Some("Sat Aug 26, 2023 07:27:27 UTC"));
+    /// assert_eq!(quicktext.url.as_deref(), Some("https://rlaunch.me/crew7"));
+    /// assert_eq!(quicktext.tags, ["spacex"]);
+    /// ```
+    pub fn parse_quicktext(&self) -> Option<QuickText> {
+        QuickText::parse(&self.quicktext)
+    }
+
+    /// Get the first media entry featured on the launch page or on the launch day, if any.
+    pub fn featured_media(&self) -> Option<&Media> {
+        self.media
+            .iter()
+            .find(|media| media.featured || media.ldfeatured)
+    }
+
+    /// Get the YouTube links of the media entries that have a video id.
+    pub fn youtube_urls(&self) -> Vec<String> {
+        self.media.iter().filter_map(Media::youtube_url).collect()
+    }
+
+    /// Get the link of the best video of the launch, preferring the featured media.
+    ///
+    /// ```
+    /// use rocket_launch_live::api_models::{Launch, Media};
+    ///
+    /// let launch = Launch {
+    ///     media: vec![
+    ///         Media { youtube_vidid: String::from("first"), ..Default::default() },
+    ///         Media { youtube_vidid: String::from("live"), featured: true, ..Default::default() },
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     launch.primary_video_url().as_deref(),
+    ///     Some("https://www.youtube.com/watch?v=live")
+    /// );
+    /// ```
+    pub fn primary_video_url(&self) -> Option<String> {
+        self.featured_media()
+            .and_then(Media::video_url)
+            .or_else(|| self.media.iter().find_map(Media::video_url))
+    }
+
+    /// Get the launch description as plain text, without HTML tags or entities.
+    #[cfg(feature = "html")]
+    pub fn launch_description_plain(&self) -> String {
+        crate::html::to_plain_text(&self.launch_description)
+    }
+
+    /// Get the mission description as plain text, without HTML tags or entities.
+    #[cfg(feature = "html")]
+    pub fn mission_description_plain(&self) -> Option<String> {
+        self.mission_description
+            .as_deref()
+            .map(crate::html::to_plain_text)
+    }
+
+    fn mission_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .missions
+            .iter()
+            .map(|mission| normalize(&mission.name))
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        if names.is_empty() {
+            names.push(normalize(&self.name));
+            names.retain(|name| !name.is_empty());
+        }
+
+        names
+    }
+}
+
+/// Lightweight launch with only the fields needed to list launches.
+///
+/// The API has no parameter to trim the records it sends, so the whole launches are still
+/// transferred, but every other field is skipped instead of parsed. Use it as the result type of
+/// the launch methods, e.g. `client.launches::<LaunchSummary>(None)`.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchSummary {
+    pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub name: String,
+    /// Machine sortable launch date (a Unix timestamp), see [`LaunchSummary::sort_key`].
+    #[serde(deserialize_with = "null_as_default")]
+    pub sort_date: String,
+    /// Human readable launch date for display only, not suitable for sorting.
+    #[serde(deserialize_with = "null_as_default")]
+    pub date_str: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub slug: String,
+    /// Name of the launch provider, read from and written to the provider record.
+    #[serde(
+        rename = "provider",
+        deserialize_with = "provider_name",
+        serialize_with = "provider_record"
+    )]
+    pub provider_name: String,
+}
+
+impl LaunchSummary {
+    /// Get the canonical ordering value of the launch, as [`Launch::sort_key`] does.
+    pub fn sort_key(&self) -> DateTime<Utc> {
+        self.launch_datetime().unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Get the launch date parsed from sort_date, or None if it's missing or unparseable.
+    pub fn launch_datetime(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.sort_date)
+    }
+}
+
+/// Outcome of a launch, decoded from the integer codes used by the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i64", into = "i64")]
+pub enum LaunchResult {
+    /// Code 1.
+    Success,
+    /// Code 0.
+    Failure,
+    /// Code 2.
+    PartialFailure,
+    /// Code 3, the vehicle is still in flight.
+    InFlight,
+    /// Any code not known to this crate.
+    Unknown(i64),
+}
+
+impl From<i64> for LaunchResult {
+    fn from(code: i64) -> Self {
+        match code {
+            1 => LaunchResult::Success,
+            0 => LaunchResult::Failure,
+            2 => LaunchResult::PartialFailure,
+            3 => LaunchResult::InFlight,
+            code => LaunchResult::Unknown(code),
+        }
+    }
+}
+
+impl From<LaunchResult> for i64 {
+    fn from(result: LaunchResult) -> Self {
+        match result {
+            LaunchResult::Success => 1,
+            LaunchResult::Failure => 0,
+            LaunchResult::PartialFailure => 2,
+            LaunchResult::InFlight => 3,
+            LaunchResult::Unknown(code) => code,
+        }
+    }
+}
+
+/// Weather forecast of a launch, typed from the raw weather fields.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Weather {
+    pub summary: Option<String>,
+    /// Temperature in degrees Fahrenheit.
+    pub temp_f: Option<f64>,
+    pub condition: Option<String>,
+    pub wind_mph: Option<f64>,
+    pub icon: Option<String>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// Components of the quicktext of a launch, split into their parts.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct QuickText {
+    pub vehicle: Option<String>,
+    pub mission: Option<String>,
+    /// Launch date as written in the quicktext, for display only.
+    pub date: Option<String>,
+    /// Launch location, as written after the date.
+    pub location: Option<String>,
+    /// Link to the launch page, with the info and stream.
+    pub url: Option<String>,
+    /// Hashtags, without the leading `#`.
+    pub tags: Vec<String>,
+}
+
+impl QuickText {
+    /// Parse a quicktext of the form `vehicle - mission - date - location - url for info/stream
+    /// #tags`.
+    ///
+    /// The parts are separated by ` - ` or line breaks, and any of them may be missing. The URL
+    /// and hashtags are picked wherever they are, and parts labeled `Vehicle:`, `Mission:`,
+    /// `Date:` or `Location:` go to their field. The date is recognized by its month and year or
+    /// by UTC, and a single part after it is the location. The parts before it fill the vehicle
+    /// and mission in order, only when there are as many of them as missing fields, so that none
+    /// is mislabeled.
+    fn parse(quicktext: &str) -> Option<QuickText> {
+        let mut parsed = QuickText::default();
+        let mut parts = Vec::new();
+
+        for part in quicktext.lines().flat_map(|line| line.split(" - ")) {
+            let mut words = Vec::new();
+
+            for word in part.split_whitespace() {
+                if word.starts_with("http://") || word.starts_with("https://") {
+                    parsed.url.get_or_insert_with(|| word.to_string());
+                } else if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+                    parsed.tags.push(tag.to_string());
+                } else {
+                    words.push(word);
+                }
+            }
+
+            if parsed.url.is_some() && words.join(" ") == "for info/stream" {
+                continue;
+            }
+
+            if !words.is_empty() {
+                parts.push(words.join(" "));
+            }
+        }
+
+        let mut unlabeled = Vec::new();
+
+        for part in parts {
+            let field = match part.split_once(':') {
+                Some((label, value)) => match label.trim().to_ascii_lowercase().as_str() {
+                    "vehicle" => Some((&mut parsed.vehicle, value)),
+                    "mission" => Some((&mut parsed.mission, value)),
+                    "date" => Some((&mut parsed.date, value)),
+                    "location" => Some((&mut parsed.location, value)),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            match field {
+                Some((field, value)) => *field = Some(value.trim().to_string()),
+                None => unlabeled.push(part),
+            }
+        }
+
+        let date = unlabeled.iter().position(|part| is_quicktext_date(part));
+        let after = date.map_or(Vec::new(), |date| unlabeled.split_off(date + 1));
+
+        if let Some(date) = date {
+            let date = unlabeled.remove(date);
+            parsed.date.get_or_insert(date);
+        }
+
+        if let [location] = after.as_slice() {
+            parsed.location.get_or_insert_with(|| location.clone());
+        }
+
+        let missing: Vec<&mut Option<String>> = [&mut parsed.vehicle, &mut parsed.mission]
+            .into_iter()
+            .filter(|field| field.is_none())
+            .collect();
+
+        if missing.len() == unlabeled.len() {
+            for (field, part) in missing.into_iter().zip(unlabeled) {
+                *field = Some(part);
+            }
+        }
+
+        if parsed == QuickText::default() {
+            None
+        } else {
+            Some(parsed)
+        }
+    }
+}
+
+/// Check if a quicktext part is a date, with a month name or abbreviation and a year, or UTC.
+fn is_quicktext_date(part: &str) -> bool {
+    const MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    let words: Vec<&str> = part.split(|c: char| !c.is_alphanumeric()).collect();
+    let has_month = words
+        .iter()
+        .any(|word| word.len() >= 3 && MONTHS.iter().any(|month| month.starts_with(word)));
+    let has_year = words
+        .iter()
+        .any(|word| word.len() == 4 && word.chars().all(|c| c.is_ascii_digit()));
+
+    words.contains(&"UTC") || (has_month && has_year)
+}
+
+/// Launch window times in UTC.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct LaunchWindow {
+    pub open: Option<DateTime<Utc>>,
+    pub close: Option<DateTime<Utc>>,
+    pub t0: Option<DateTime<Utc>>,
+}
+
+impl LaunchWindow {
+    /// Get the time span between the window open and close, if both are known.
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.close? - self.open?)
+    }
+
+    /// Check if an instant falls inside the window (or matches T-0 when there's no window).
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        match self.bounds() {
+            Some((start, end)) => start <= instant && instant <= end,
+            None => false,
+        }
+    }
+
+    /// Check if the window opens and closes at the same instant.
+    pub fn is_instantaneous(&self) -> bool {
+        matches!(self.bounds(), Some((start, end)) if start == end)
+    }
+
+    fn bounds(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = self.open.or(self.t0)?;
+
+        Some((start, self.close.unwrap_or(start)))
+    }
+}
+
+/// Models identified by an optional id.
+pub trait HasId {
+    /// Get the id of the record, if any.
+    fn id(&self) -> Option<i64>;
+}
+
+macro_rules! impl_has_id {
+    ($($model:ty),*) => {
+        $(
+            impl HasId for $model {
+                fn id(&self) -> Option<i64> {
+                    self.id
+                }
+            }
+        )*
+    };
+}
+
+impl_has_id!(
+    Company,
+    Launch,
+    LaunchSummary,
+    Location,
+    Mission,
+    Pad,
+    Provider,
+    Tag,
+    Vehicle
+);
+
+/// Remove the records with an id already seen, keeping the first one and the records without id.
+pub fn dedup_by_id<T: HasId>(items: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::new();
+
+    items
+        .into_iter()
+        .filter(|item| item.id().is_none_or(|id| seen.insert(id)))
+        .collect()
+}
+
+/// Sort the launches chronologically by their sort_date, see [`Launch::sort_key`].
+///
+/// Launches with a missing or unparseable date go last. The sort is stable, so launches with the
+/// same date keep their relative order.
+pub fn sort_by_date(launches: &mut [Launch]) {
+    launches.sort_by_key(Launch::sort_key);
+}
+
+/// Map the records by id, leaving out the records without id.
+pub fn index_by_id<T: HasId>(items: impl IntoIterator<Item = T>) -> HashMap<i64, T> {
+    items
+        .into_iter()
+        .filter_map(|item| Some((item.id()?, item)))
+        .collect()
+}
+
+/// Launch with the full records of its provider, vehicle and location.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrichedLaunch {
+    pub launch: Launch,
+    pub provider: Option<Company>,
+    pub vehicle: Option<Vehicle>,
+    pub location: Option<Location>,
+}
+
+/// Records matching a search term, grouped by endpoint.
+#[derive(Default, Debug)]
+pub struct SearchResults {
+    pub launches: Vec<Launch>,
+    pub missions: Vec<Mission>,
+    pub vehicles: Vec<Vehicle>,
+    pub companies: Vec<Company>,
+    /// Endpoints that failed, with their error. Their records are left empty.
+    pub errors: Vec<(&'static str, RllError)>,
+}
+
+impl SearchResults {
+    /// Check if every endpoint answered.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Schedule change of a launch between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlipEvent {
+    pub launch_id: i64,
+    pub old_date: DateTime<Utc>,
+    pub new_date: DateTime<Utc>,
+}
+
+/// Find the launches whose date changed between an old and a new snapshot.
+///
+/// Launches are matched by id and their dates are taken from the parsed T-0 or, when there's no
+/// T-0, the sort date. Launches missing from either snapshot or without a date are skipped.
+pub fn detect_slips(old: &[Launch], new: &[Launch]) -> Vec<SlipEvent> {
+    let scheduled = |launch: &Launch| {
+        launch
+            .t0
+            .as_deref()
+            .and_then(parse_datetime)
+            .or_else(|| launch.launch_datetime())
+    };
+    let old_dates: HashMap<i64, DateTime<Utc>> = old
+        .iter()
+        .filter_map(|launch| Some((launch.id?, scheduled(launch)?)))
+        .collect();
+
+    new.iter()
+        .filter_map(|launch| {
+            let launch_id = launch.id?;
+            let old_date = *old_dates.get(&launch_id)?;
+            let new_date = scheduled(launch)?;
+
+            (old_date != new_date).then_some(SlipEvent {
+                launch_id,
+                old_date,
+                new_date,
+            })
+        })
+        .collect()
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Provider {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
     pub slug: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Vehicle {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
     pub company_id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub slug: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Pad {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub location: Location,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Location {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
     pub state: Option<String>,
     pub statename: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
     pub country: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub slug: String,
+    #[serde(default, deserialize_with = "lenient_f64")]
+    pub latitude: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_f64")]
+    pub longitude: Option<f64>,
+}
+
+impl Location {
+    /// Get the known country code matching the country name, if any.
+    pub fn country_code(&self) -> Option<CountryCode> {
+        CountryCode::from_name(&self.country)
+    }
+
+    /// Get a label like "Cape Canaveral, FL, United States", leaving out the missing parts.
+    pub fn display_name(&self) -> String {
+        let state = self.state.as_deref().or(self.statename.as_deref());
+
+        [Some(self.name.as_str()), state, Some(self.country.as_str())]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Mission {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub name: String,
+    /// Mission description, None when the API sends it absent, null or empty.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub description: Option<String>,
 }
 
+impl Mission {
+    /// Get the description as plain text, without HTML tags or entities (empty when missing).
+    #[cfg(feature = "html")]
+    pub fn description_plain(&self) -> String {
+        self.description
+            .as_deref()
+            .map(crate::html::to_plain_text)
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EstDate {
     pub month: Option<i64>,
     pub day: Option<i64>,
     pub year: Option<i64>,
-    pub quarter: Value,
+    /// Quarter of the year (1 to 4), when only the quarter is known.
+    #[serde(default, deserialize_with = "lenient_i64")]
+    pub quarter: Option<i64>,
+}
+
+impl EstDate {
+    /// Get the estimated date, if the year, month and day are all known and form a valid date.
+    pub fn as_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            self.year?.try_into().ok()?,
+            self.month?.try_into().ok()?,
+            self.day?.try_into().ok()?,
+        )
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Tag {
     pub id: Option<i64>,
+    #[serde(deserialize_with = "null_as_default")]
     pub text: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Medum {
+#[serde(default)]
+pub struct Media {
     pub id: Option<i64>,
     pub media_url: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
     pub youtube_vidid: String,
+    #[serde(deserialize_with = "null_as_default")]
     pub featured: bool,
+    #[serde(deserialize_with = "null_as_default")]
     pub ldfeatured: bool,
+    #[serde(deserialize_with = "null_as_default")]
     pub approved: bool,
 }
+
+/// Former name of [`Media`].
+#[deprecated(note = "renamed to Media")]
+pub type Medum = Media;
+
+impl Media {
+    /// Get the YouTube link of the video, if there's a video id.
+    pub fn youtube_url(&self) -> Option<String> {
+        let id = self.youtube_vidid.trim();
+
+        (!id.is_empty()).then(|| format!("https://www.youtube.com/watch?v={}", id))
+    }
+
+    /// Get the YouTube link of the video, or else the media URL.
+    fn video_url(&self) -> Option<String> {
+        self.youtube_url().or_else(|| {
+            self.media_url
+                .as_deref()
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(String::from)
+        })
+    }
+}
+
+/// Lowercase a name and drop everything but letters and digits, for loose comparisons.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Parse an API timestamp, either RFC 3339 or the shorter `YYYY-MM-DDTHH:MM` form with Z or offset.
+pub(crate) fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M%#z"))
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%MZ").map(|dt| dt.and_utc()))
+        .ok()
+}
+
+/// Parse either a Unix timestamp or an API timestamp.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    value
+        .parse()
+        .ok()
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .or_else(|| parse_datetime(value))
+}
+
+/// Get a raw value as a string, if it's a non-blank string or a number.
+fn value_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) if !value.trim().is_empty() => Some(value.trim().to_string()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Get a raw value as a number, if it's a number or a string holding one.
+fn value_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(value) => value.as_f64(),
+        Value::String(value) => value.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Deserialize a value, treating null like a missing value.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Deserialize the name of a provider record, treating null like a provider without name.
+fn provider_name<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<Provider>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .name)
+}
+
+/// Serialize a provider name as a provider record, the shape read by [`provider_name`].
+fn provider_record<S: Serializer>(name: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut provider = serializer.serialize_struct("Provider", 1)?;
+    provider.serialize_field("name", name)?;
+    provider.end()
+}
+
+/// Deserialize an optional number, from a number or a string holding one, or else as None.
+fn lenient_f64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    Ok(value_f64(&Value::deserialize(deserializer)?))
+}
+
+/// Deserialize an optional integer, from a number or a string holding one, or else as None.
+fn lenient_i64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+
+    Ok(value_f64(&value)
+        .filter(|value| value.fract() == 0.0)
+        .map(|value| value as i64))
+}
+
+/// Deserialize an optional string, treating an empty or blank string like null.
+fn empty_as_none<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value: Option<String> = Option::deserialize(deserializer)?;
+
+    Ok(value.filter(|value| !value.trim().is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_lists_are_empty() {
+        let launch: Launch = serde_json::from_str(r#"{"id": 1, "name": "x"}"#).unwrap();
+
+        assert!(launch.missions.is_empty());
+        assert!(launch.tags.is_empty());
+        assert!(launch.media.is_empty());
+    }
+
+    #[test]
+    fn null_fields_are_defaulted() {
+        let body = r#"{
+            "result": [{
+                "id": 1,
+                "name": null,
+                "sort_date": null,
+                "provider": null,
+                "vehicle": {"id": 2, "name": null, "slug": null},
+                "pad": {"id": 3, "name": "SLC-40", "location": null},
+                "est_date": null,
+                "slug": null,
+                "quicktext": null,
+                "media": [{"youtube_vidid": null, "featured": null}],
+                "tags": [{"id": 4, "text": null}],
+                "suborbital": null,
+                "modified": null
+            }]
+        }"#;
+        let resp: Response<Launch> = serde_json::from_str(body).unwrap();
+        let launch = &resp.result[0];
+
+        assert_eq!(launch.id, Some(1));
+        assert_eq!(launch.name, "");
+        assert_eq!(launch.provider, Provider::default());
+        assert_eq!(launch.vehicle.id, Some(2));
+        assert_eq!(launch.pad.location, Location::default());
+        assert!(!launch.suborbital);
+        assert!(!launch.media[0].featured);
+        assert_eq!(launch.tags[0].text, "");
+
+        let body = r#"{"result": [{"id": 1, "name": null, "inactive": null, "country": null}]}"#;
+        let resp: Response<Company> = serde_json::from_str(body).unwrap();
+
+        assert_eq!(resp.result[0].country, Country::default());
+    }
+
+    #[test]
+    fn same_mission() {
+        let mission = |name: &str| Mission {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let a = Launch {
+            cospar_id: Some(String::from("2023-123A")),
+            name: String::from("Falcon 9 | Starlink"),
+            ..Default::default()
+        };
+        let b = Launch {
+            cospar_id: Some(String::from(" 2023-123a ")),
+            ..Default::default()
+        };
+        let c = Launch {
+            missions: vec![mission("Crew-7")],
+            ..Default::default()
+        };
+        let d = Launch {
+            name: String::from("Falcon 9"),
+            missions: vec![mission(""), mission("crew 7")],
+            ..Default::default()
+        };
+
+        assert!(!Launch::default().same_mission_as(&Launch::default()));
+        assert!(a.same_mission_as(&b));
+        assert!(c.same_mission_as(&d));
+        assert!(!a.same_mission_as(&c));
+    }
+
+    #[test]
+    fn launch_summary_round_trip() {
+        let summary = LaunchSummary {
+            id: Some(1),
+            name: String::from("Starlink"),
+            provider_name: String::from("SpaceX"),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&summary).unwrap();
+
+        assert!(json.contains(r#""provider":{"name":"SpaceX"}"#));
+        assert_eq!(
+            serde_json::from_str::<LaunchSummary>(&json).unwrap(),
+            summary
+        );
+    }
+
+    #[test]
+    fn quicktext_with_missing_parts() {
+        let parse = |quicktext: &str| QuickText::parse(quicktext).unwrap();
+
+        let quicktext = parse("Falcon 9 - Sat Aug 26, 2023 07:27:27 UTC - Cape Canaveral SFS");
+
+        assert_eq!(quicktext.vehicle, None);
+        assert_eq!(quicktext.mission, None);
+        assert_eq!(
+            quicktext.date.as_deref(),
+            Some("Sat Aug 26, 2023 07:27:27 UTC")
+        );
+        assert_eq!(quicktext.location.as_deref(), Some("Cape Canaveral SFS"));
+
+        let quicktext = parse("Falcon 9 - Mission: Crew-7 - Aug 26, 2023 #spacex");
+
+        assert_eq!(quicktext.vehicle.as_deref(), Some("Falcon 9"));
+        assert_eq!(quicktext.mission.as_deref(), Some("Crew-7"));
+        assert_eq!(quicktext.date.as_deref(), Some("Aug 26, 2023"));
+        assert_eq!(quicktext.location, None);
+
+        let quicktext = parse("Long March 2D - Mars 2020");
+
+        assert_eq!(quicktext.vehicle.as_deref(), Some("Long March 2D"));
+        assert_eq!(quicktext.mission.as_deref(), Some("Mars 2020"));
+        assert_eq!(quicktext.date, None);
+    }
+}
@@ -0,0 +1,176 @@
+//! Synchronous API client, for code that doesn't run inside an async runtime.
+//!
+//! The parameter builders and the models are the same as for the async client, only the requests
+//! are sent differently.
+//!
+//! ```no_run
+//! use rocket_launch_live::api_models::{Launch, Response};
+//! use rocket_launch_live::blocking::RocketLaunchLive;
+//! use rocket_launch_live::LaunchParamsBuilder;
+//!
+//! let client = RocketLaunchLive::new("key");
+//! let params = LaunchParamsBuilder::new().country_code("US").build();
+//! let resp: Response<Launch> = client.launches(Some(params))?;
+//! # Ok::<(), rocket_launch_live::RllError>(())
+//! ```
+use crate::api_models::Response;
+use crate::{base_url, parse_status_response, rate_limit, Params, RllError, USER_AGENT};
+use serde::de::DeserializeOwned;
+
+/// Synchronous API client containing all the public endpoint methods.
+///
+/// The underlying HTTP client runs its own runtime, so it must not be created or used from within
+/// an async runtime.
+#[derive(Clone)]
+pub struct RocketLaunchLive {
+    key: String,
+    url: String,
+    client: reqwest::blocking::Client,
+    accept_language: Option<String>,
+    user_agent: String,
+}
+
+impl RocketLaunchLive {
+    /// Create a new API client with an API key.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self::with_client(key, reqwest::blocking::Client::new())
+    }
+
+    /// Create a new API client with an API key, or an error if the key is empty.
+    pub fn try_new(key: impl Into<String>) -> Result<Self, RllError> {
+        let key = key.into();
+
+        if key.trim().is_empty() {
+            return Err(RllError::EmptyApiKey);
+        }
+
+        Ok(Self::new(key))
+    }
+
+    /// Create a new API client with an API key, sending the requests through an HTTP client.
+    pub fn with_client(key: impl Into<String>, client: reqwest::blocking::Client) -> Self {
+        Self {
+            key: key.into(),
+            url: String::from("https://fdo.rocketlaunch.live"),
+            client,
+            accept_language: None,
+            user_agent: String::from(USER_AGENT),
+        }
+    }
+
+    /// Create a new API client with an API key, sending the requests to another base URL.
+    ///
+    /// The URL must be an absolute HTTP(S) URL, and any trailing slash is removed.
+    pub fn with_base_url(key: impl Into<String>, url: &str) -> Result<Self, RllError> {
+        Ok(Self {
+            url: base_url(url)?.to_string(),
+            ..Self::new(key)
+        })
+    }
+
+    /// Set the Accept-Language header sent with every request (unset uses the server default).
+    pub fn accept_language(mut self, language: impl Into<String>) -> Self {
+        self.accept_language = Some(language.into());
+
+        self
+    }
+
+    /// Set the User-Agent header sent with every request (`rocket_launch_live/<version>` by
+    /// default).
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+
+        self
+    }
+
+    fn request<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        let url = format!(
+            "{}/json/{}?{}",
+            self.url,
+            endpoint,
+            params.unwrap_or_default()
+        );
+        let mut req = self
+            .client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", self.key))
+            .header("User-Agent", &self.user_agent);
+
+        if let Some(language) = &self.accept_language {
+            req = req.header("Accept-Language", language);
+        }
+
+        let resp = req.send()?;
+
+        if resp.status().is_server_error() {
+            resp.error_for_status_ref()?;
+        }
+
+        let rate_limit = rate_limit(resp.headers());
+        let status = resp.status();
+        let mut resp = parse_status_response(status, &resp.text()?)?;
+        resp.rate_limit = rate_limit;
+
+        Ok(resp)
+    }
+
+    /// Retrieve all companies in the database (optionally filtered by params) or an error.
+    pub fn companies<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("companies", params)
+    }
+
+    /// Retrieve all launches in the database (optionally filtered by params) or an error.
+    pub fn launches<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("launches", params)
+    }
+
+    /// Retrieve all locations in the database (optionally filtered by params) or an error.
+    pub fn locations<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("locations", params)
+    }
+
+    /// Retrieve all missions in the database (optionally filtered by params) or an error.
+    pub fn missions<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("missions", params)
+    }
+
+    /// Retrieve all pads in the database (optionally filtered by params) or an error.
+    pub fn pads<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("pads", params)
+    }
+
+    /// Retrieve all tags in the database (optionally filtered by params) or an error.
+    pub fn tags<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("tags", params)
+    }
+
+    /// Retrieve all vehicles in the database (optionally filtered by params) or an error.
+    pub fn vehicles<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("vehicles", params)
+    }
+}
@@ -0,0 +1,224 @@
+//! In-memory cache of API responses, revalidated with conditional requests.
+use crate::api_models::Response;
+use crate::{
+    parse_response, parse_status_response, rate_limit, Params, RllError, RocketLaunchLive,
+};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Cached response body of a request, with the validators sent by the server.
+struct Entry {
+    body: String,
+    fetched_at: Instant,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// API client wrapper caching the responses of GET requests, keyed by endpoint and params.
+///
+/// A cached response younger than the TTL is returned without any request. An older one is
+/// revalidated with If-None-Match and If-Modified-Since, when the server sent an ETag or a
+/// Last-Modified header, and returned again if the server answers 304 Not Modified. When the
+/// cache is full, the response fetched (or revalidated) longest ago is evicted, however recently
+/// it was read from the cache.
+///
+/// The requests are sent directly by the cache, not through the send path of the wrapped client,
+/// so they are never retried (see [`RocketLaunchLive::with_retries`]), and they aren't recorded
+/// to a cassette, counted by the metrics or traced.
+pub struct CachingClient {
+    client: RocketLaunchLive,
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl CachingClient {
+    /// Wrap an API client, keeping responses fresh for ttl (up to 256 responses by default).
+    pub fn new(client: RocketLaunchLive, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            max_entries: 256,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the maximum number of cached responses.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+
+        self
+    }
+
+    /// Get the wrapped API client, to send requests that bypass the cache.
+    pub fn client(&self) -> &RocketLaunchLive {
+        &self.client
+    }
+
+    /// Remove every cached response.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    /// Send a GET request to an endpoint (optionally filtered by params), through the cache.
+    ///
+    /// The endpoint is appended to the base URL after `/json/`, as in [`RocketLaunchLive::get`].
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        let url = self.client.endpoint_url(endpoint, params);
+        let (etag, last_modified) = {
+            let entries = self.lock()?;
+
+            match entries.get(&url) {
+                Some(entry) if entry.fetched_at.elapsed() < self.ttl => {
+                    return parse_response(&entry.body)
+                }
+                Some(entry) => (entry.etag.clone(), entry.last_modified.clone()),
+                None => (None, None),
+            }
+        };
+        let mut req = self.client.authorize(self.client.client.get(&url));
+
+        if let Some(etag) = etag {
+            req = req.header(IF_NONE_MATCH, etag);
+        }
+
+        if let Some(last_modified) = last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let resp = req.send().await?;
+
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            let mut entries = self.lock()?;
+
+            if let Some(entry) = entries.get_mut(&url) {
+                entry.fetched_at = Instant::now();
+
+                return parse_response(&entry.body);
+            }
+        }
+
+        if resp.status().is_server_error() {
+            resp.error_for_status_ref()?;
+        }
+
+        let header = |name| {
+            resp.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        let rate_limit = rate_limit(resp.headers());
+        let status = resp.status();
+        let body = resp.text().await?;
+        let mut parsed: Response<T> = parse_status_response(status, &body)?;
+        parsed.rate_limit = rate_limit;
+
+        if status.is_success() {
+            let entry = Entry {
+                body,
+                fetched_at: Instant::now(),
+                etag,
+                last_modified,
+            };
+
+            self.insert(url, entry)?;
+        }
+
+        Ok(parsed)
+    }
+
+    fn insert(&self, url: String, entry: Entry) -> Result<(), RllError> {
+        let mut entries = self.lock()?;
+
+        if !entries.contains_key(&url) && entries.len() >= self.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.fetched_at)
+                .map(|(url, _)| url.clone());
+
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(url, entry);
+
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, Entry>>, RllError> {
+        self.entries
+            .lock()
+            .map_err(|_| std::io::Error::other("The cache lock is poisoned.").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_models::{Company, Launch};
+    use crate::tests::{MockResponse, MockServer, COMPANY, LAUNCH};
+
+    #[tokio::test]
+    async fn fresh_response_is_cached() {
+        let server = MockServer::start(LAUNCH).await;
+        let cache = CachingClient::new(server.client(), Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let resp: Response<Launch> = cache.get("launches", None).await.unwrap();
+
+            assert_eq!(resp.result.len(), 1);
+        }
+
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn stale_response_is_revalidated() {
+        let server = MockServer::scripted(vec![
+            MockResponse::ok(LAUNCH)
+                .header("ETag", "\"v1\"")
+                .header("Last-Modified", "Fri, 01 Sep 2023 12:00:00 GMT"),
+            MockResponse::status(304),
+        ])
+        .await;
+        let cache = CachingClient::new(server.client(), Duration::ZERO);
+
+        for _ in 0..2 {
+            let resp: Response<Launch> = cache.get("launches", None).await.unwrap();
+
+            assert_eq!(resp.result.len(), 1);
+        }
+
+        let requests = server.requests();
+
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].contains("if-none-match: \"v1\"\r\n"));
+        assert!(requests[1].contains("if-modified-since: Fri, 01 Sep 2023 12:00:00 GMT\r\n"));
+    }
+
+    #[tokio::test]
+    async fn full_cache_evicts() {
+        let server = MockServer::start(COMPANY).await;
+        let cache = CachingClient::new(server.client(), Duration::from_secs(60)).max_entries(1);
+
+        for endpoint in ["companies", "vehicles", "companies"] {
+            let _: Response<Company> = cache.get(endpoint, None).await.unwrap();
+        }
+
+        assert_eq!(server.requests().len(), 3);
+    }
+}
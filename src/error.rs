@@ -0,0 +1,224 @@
+//! Error type definitions.
+use chrono::NaiveDate;
+use std::error::Error;
+use std::{fmt, io};
+
+/// Errors returned by the API client.
+#[derive(Debug)]
+pub enum RllError {
+    /// The API key is empty.
+    EmptyApiKey,
+    /// The RLL_API_KEY environment variable isn't set, or isn't valid Unicode.
+    MissingApiKey,
+    /// The base URL isn't an absolute HTTP(S) URL, or the proxy URL is invalid.
+    InvalidUrl(String),
+    /// The API answered with an error message.
+    Api(String),
+    /// The request could not be sent or its response could not be read.
+    Http(reqwest::Error),
+    /// The request took longer than the timeout set on the client.
+    Timeout(reqwest::Error),
+    /// The response is not the JSON expected.
+    Json(serde_json::Error),
+    /// The response received is not the JSON expected, e.g. an HTML error page.
+    UnexpectedResponse {
+        /// HTTP status of the response.
+        status: reqwest::StatusCode,
+        /// Start of the response body.
+        body: String,
+        /// Error parsing the body.
+        error: serde_json::Error,
+    },
+    /// Reading or writing a local file failed.
+    Io(io::Error),
+    /// The parameters are invalid.
+    Params(BuilderError),
+    /// The endpoint doesn't support the operation.
+    Unsupported(String),
+    /// The response has no total, so the records can't be counted.
+    MissingTotal,
+    /// No company has the provider name.
+    UnknownProvider(String),
+    /// Several companies match the provider name, and none of them exactly.
+    AmbiguousProvider {
+        /// Name looked up.
+        name: String,
+        /// Names of the matching companies.
+        matches: Vec<String>,
+    },
+    /// More related records would be looked up than the cap allows.
+    TooManyLookups {
+        /// Number of lookups needed.
+        needed: usize,
+        /// Maximum number of lookups allowed.
+        max: usize,
+    },
+    /// The request still failed after being retried.
+    Retries {
+        /// Number of attempts made, including the first one.
+        attempts: u32,
+        /// Error of the last attempt.
+        error: Box<RllError>,
+    },
+}
+
+impl RllError {
+    /// Get the number of attempts made before failing (1 unless the request was retried).
+    pub fn attempts(&self) -> u32 {
+        match self {
+            RllError::Retries { attempts, .. } => *attempts,
+            _ => 1,
+        }
+    }
+
+    /// Check if the error is a network error or a 5xx response, which may go away on a retry.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            RllError::Http(e) => !e.is_builder() && e.status().is_none_or(|s| s.is_server_error()),
+            RllError::Timeout(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for RllError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RllError::EmptyApiKey => write!(f, "The API key is empty."),
+            RllError::MissingApiKey => {
+                write!(f, "The RLL_API_KEY environment variable is not set.")
+            }
+            RllError::InvalidUrl(url) => write!(f, "Invalid URL: {}", url),
+            RllError::Api(message) => write!(f, "The API returned an error: {}", message),
+            RllError::Http(e) => write!(f, "The request failed: {}", e),
+            RllError::Timeout(e) => write!(f, "The request timed out: {}", e),
+            RllError::Json(e) => write!(f, "Could not parse the response: {}", e),
+            RllError::UnexpectedResponse {
+                status,
+                body,
+                error,
+            } => write!(
+                f,
+                "Could not parse the response ({}): {}, body: {}",
+                status, error, body
+            ),
+            RllError::Io(e) => write!(f, "Could not access the file: {}", e),
+            RllError::Params(e) => write!(f, "{}", e),
+            RllError::Unsupported(message) => write!(f, "{}", message),
+            RllError::MissingTotal => write!(f, "The response has no total."),
+            RllError::UnknownProvider(name) => write!(f, "No provider named {:?}.", name),
+            RllError::AmbiguousProvider { name, matches } => write!(
+                f,
+                "The provider name {:?} is ambiguous, it matches: {}.",
+                name,
+                matches.join(", ")
+            ),
+            RllError::TooManyLookups { needed, max } => write!(
+                f,
+                "{} lookups are needed, more than the maximum of {}.",
+                needed, max
+            ),
+            RllError::Retries { attempts, error } => {
+                write!(f, "{} (after {} attempts)", error, attempts)
+            }
+        }
+    }
+}
+
+impl Error for RllError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RllError::Http(e) => Some(e),
+            RllError::Timeout(e) => Some(e),
+            RllError::Json(e) => Some(e),
+            RllError::UnexpectedResponse { error, .. } => Some(error),
+            RllError::Io(e) => Some(e),
+            RllError::Params(e) => Some(e),
+            RllError::Retries { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for RllError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            RllError::Timeout(e)
+        } else {
+            RllError::Http(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for RllError {
+    fn from(e: serde_json::Error) -> Self {
+        RllError::Json(e)
+    }
+}
+
+impl From<io::Error> for RllError {
+    fn from(e: io::Error) -> Self {
+        RllError::Io(e)
+    }
+}
+
+impl From<BuilderError> for RllError {
+    fn from(e: BuilderError) -> Self {
+        RllError::Params(e)
+    }
+}
+
+/// Errors found while building the API parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuilderError {
+    /// Parameters that were given a value that could not be parsed.
+    InvalidFields(Vec<&'static str>),
+    /// A direction that isn't `asc` or `desc`.
+    InvalidDirection(String),
+    /// A country code that isn't a known ISO 3166-1 alpha-2 code.
+    UnknownCountryCode(String),
+    /// A state_abbr without the US country_code, as states are only known in the US.
+    StateWithoutCountry(String),
+    /// An after_date later than the before_date, which no launch can match.
+    InvertedDateRange {
+        after_date: NaiveDate,
+        before_date: NaiveDate,
+    },
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderError::InvalidFields(fields) => {
+                write!(f, "Could not parse parameters: {}.", fields.join(", "))
+            }
+            BuilderError::InvalidDirection(direction) => {
+                write!(
+                    f,
+                    "Invalid direction {:?}, expected asc or desc.",
+                    direction
+                )
+            }
+            BuilderError::UnknownCountryCode(code) => write!(
+                f,
+                "Unknown country code {:?}, expected an ISO 3166-1 alpha-2 code.",
+                code
+            ),
+            BuilderError::StateWithoutCountry(state) => write!(
+                f,
+                "The state_abbr {:?} requires the US country_code.",
+                state
+            ),
+            BuilderError::InvertedDateRange {
+                after_date,
+                before_date,
+            } => write!(
+                f,
+                "The after_date {} is later than the before_date {}.",
+                after_date, before_date
+            ),
+        }
+    }
+}
+
+impl Error for BuilderError {}
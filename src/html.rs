@@ -0,0 +1,115 @@
+//! Minimal HTML to plain text conversion for description fields.
+
+/// Strip the tags from an HTML fragment and decode its character entities.
+///
+/// Line breaks and paragraph ends become newlines, every other tag is dropped. The basic entities,
+/// the typographic quotes, dashes and ellipsis, and numeric entities are decoded. Unknown entities
+/// are kept as they are.
+pub(crate) fn to_plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => match rest.find('>') {
+                Some(end) => {
+                    if is_line_break(&rest[1..end]) {
+                        text.push('\n');
+                    }
+
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    text.push_str(rest);
+                    rest = "";
+                }
+            },
+            '&' => match rest
+                .find(';')
+                .and_then(|end| Some((decode(&rest[1..end])?, end)))
+            {
+                Some((decoded, end)) => {
+                    text.push(decoded);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    text.push('&');
+                    rest = &rest[1..];
+                }
+            },
+            _ => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    text.trim().to_string()
+}
+
+fn is_line_break(tag: &str) -> bool {
+    let name: String = tag
+        .trim_end_matches('/')
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '/')
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    matches!(name.as_str(), "br" | "/p" | "/div" | "/li")
+}
+
+fn decode(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        "lsquo" => Some('\u{2018}'),
+        "rsquo" => Some('\u{2019}'),
+        "ldquo" => Some('\u{201C}'),
+        "rdquo" => Some('\u{201D}'),
+        "ndash" => Some('\u{2013}'),
+        "mdash" => Some('\u{2014}'),
+        "hellip" => Some('\u{2026}'),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_breaks() {
+        assert_eq!(
+            to_plain_text("<p>One</p><p>Two<br/>Three</p>"),
+            "One\nTwo\nThree"
+        );
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(to_plain_text("Tom &amp; Jerry&#39;s"), "Tom & Jerry's");
+        assert_eq!(
+            to_plain_text("&ldquo;It&rsquo;s go&rdquo; &mdash; wait&hellip;"),
+            "\u{201C}It\u{2019}s go\u{201D} \u{2014} wait\u{2026}"
+        );
+        assert_eq!(to_plain_text("AT&T &bogus;"), "AT&T &bogus;");
+    }
+
+    #[test]
+    fn stray_less_than() {
+        assert_eq!(to_plain_text("<b>T</b> < 10 minutes"), "T < 10 minutes");
+    }
+}
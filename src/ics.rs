@@ -0,0 +1,139 @@
+//! iCalendar (RFC 5545) rendering of launches.
+use crate::api_models::Launch;
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Render the launches as an iCalendar (RFC 5545) VCALENDAR with one VEVENT per launch.
+///
+/// Launches with a T-0 or window open time start at that time, and end when the window closes,
+/// if it's known. The others become tentative all-day events on their estimated date or, failing
+/// that, on their sort date. Launches without any usable date are left out.
+///
+/// ```
+/// use rocket_launch_live::api_models::{EstDate, Launch};
+/// use rocket_launch_live::launches_to_ics;
+///
+/// let launch = Launch {
+///     name: String::from("Crew-7"),
+///     est_date: EstDate { year: Some(2023), month: Some(8), day: Some(26), quarter: None },
+///     ..Default::default()
+/// };
+/// let calendar = launches_to_ics(&[launch]);
+///
+/// assert!(calendar.contains("DTSTART;VALUE=DATE:20230826\r\n"));
+/// assert!(calendar.contains("DTEND;VALUE=DATE:20230827\r\n"));
+/// ```
+pub fn launches_to_ics(launches: &[Launch]) -> String {
+    let stamp = format_datetime(Utc::now());
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//rocket_launch_live//Launch Calendar//EN"),
+        String::from("CALSCALE:GREGORIAN"),
+    ];
+
+    for launch in launches {
+        let window = launch.window().unwrap_or_default();
+        let (start, end, tentative) = match window.t0.or(window.open) {
+            Some(start) => (
+                format!("DTSTART:{}", format_datetime(start)),
+                window
+                    .close
+                    .filter(|close| *close > start)
+                    .map(|close| format!("DTEND:{}", format_datetime(close))),
+                false,
+            ),
+            None => {
+                let Some(date) = launch
+                    .est_date
+                    .as_naive_date()
+                    .or_else(|| Some(launch.launch_datetime()?.date_naive()))
+                else {
+                    continue;
+                };
+
+                (
+                    format!("DTSTART;VALUE=DATE:{}", format_date(date)),
+                    date.succ_opt()
+                        .map(|end| format!("DTEND;VALUE=DATE:{}", format_date(end))),
+                    true,
+                )
+            }
+        };
+        let uid = match launch.id {
+            Some(id) => format!("launch-{}@rocketlaunch.live", id),
+            None => format!("{}@rocketlaunch.live", launch.slug),
+        };
+
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(format!("UID:{}", uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(start);
+        lines.extend(end);
+        lines.push(format!("SUMMARY:{}", escape(&launch.name)));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape(&launch.launch_description)
+        ));
+        lines.push(format!("LOCATION:{}", escape(&location(launch))));
+
+        if tentative {
+            lines.push(String::from("STATUS:TENTATIVE"));
+        }
+
+        lines.push(String::from("END:VEVENT"));
+    }
+
+    lines.push(String::from("END:VCALENDAR"));
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+fn location(launch: &Launch) -> String {
+    let site = launch.pad.location.display_name();
+
+    match (launch.pad.name.trim(), site.is_empty()) {
+        ("", _) => site,
+        (pad, true) => pad.to_string(),
+        (pad, false) => format!("{}, {}", pad, site),
+    }
+}
+
+fn format_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// Escape the characters with a special meaning in iCalendar text values.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line into lines of at most 75 octets, as required by RFC 5545.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut width = 0;
+
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+
+        folded.push(c);
+        width += c.len_utf8();
+    }
+
+    folded
+}
@@ -14,6 +14,7 @@
 //! Depending on which method you call, the response contains a result field of type `Vec<T>` where
 //! T can be of the type [`api_models::Company`], [`api_models::Launch`], [`api_models::Location`],
 //! [`api_models::Mission`], [`api_models::Pad`], [`api_models::Tag`] or [`api_models::Vehicle`].
+//! Failed calls return an [`RllError`], including the error messages sent by the API.
 //!
 //! This REST API provides access to a growing database of curated rocket launch data through the
 //! following endpoints:
@@ -26,9 +27,28 @@
 //! * Tags
 //! * Vehicles
 //!
+//! # Features
+//!
+//! * `blocking`: a synchronous client in the `blocking` module, for code outside an async runtime.
+//! * `html`: plain text accessors for the description fields, with the HTML stripped.
+//! * `ics`: iCalendar export of launches.
+//! * `metrics`: records the `rocket_launch_live_requests_total` counter (labelled by `endpoint`
+//!   and `status`, either the HTTP status code or `error`) and the
+//!   `rocket_launch_live_request_duration_seconds` histogram (labelled by `endpoint`) for every
+//!   request through the [`metrics`](https://docs.rs/metrics) facade.
+//! * `native-tls`: HTTPS through the system TLS library (OpenSSL on Linux).
+//! * `rustls-tls` (default): HTTPS through [`rustls`](https://docs.rs/rustls), with no system
+//!   dependencies. Without this feature or `native-tls`, only plain HTTP base URLs work.
+//! * `timezone`: local launch site times, using the [`chrono-tz`](https://docs.rs/chrono-tz)
+//!   timezone database.
+//! * `tracing`: a [`tracing`](https://docs.rs/tracing) span per request, with the endpoint and the
+//!   number of params, plus events for the URL (debug), the status and elapsed time (info) and
+//!   any error (warn).
+//! * `vcr`: records the API responses to a cassette file and replays them, for offline tests.
+//!
 //! # Examples
 //!
-//! ```
+//! ```no_run
 //! use rocket_launch_live::api_models::{Launch, Response};
 //! use rocket_launch_live::{Direction, LaunchParamsBuilder, NaiveDate, RocketLaunchLive};
 //! use std::{env, error::Error};
@@ -69,24 +89,201 @@
 //! ```
 //! [RocketLaunch.Live API]: https://www.rocketlaunch.live/api
 
-use api_models::Response;
-pub use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use api_models::{
+    dedup_by_id, Company, EnrichedLaunch, Launch, LaunchSummary, Location, Mission, Pad, RateLimit,
+    Response, SearchResults, Tag, Vehicle,
+};
+pub use bytes::Bytes;
+pub use cache::CachingClient;
+pub use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+pub use country::CountryCode;
+pub use error::{BuilderError, RllError};
+use futures::{Stream, StreamExt, TryStreamExt};
+#[cfg(feature = "ics")]
+pub use ics::launches_to_ics;
+use macros::common_setters;
+pub use paged::PagedFetcher;
 use serde::de::DeserializeOwned;
-use std::error::Error;
+use serde::Serialize;
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::net::IpAddr;
+use std::pin::pin;
+use std::str::FromStr;
+use std::time::Duration;
 
 pub mod api_models;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cache;
+mod country;
+mod error;
+#[cfg(feature = "html")]
+mod html;
+#[cfg(feature = "ics")]
+mod ics;
 mod macros;
+mod paged;
+#[cfg(feature = "timezone")]
+mod timezone;
+#[cfg(feature = "vcr")]
+mod vcr;
 
 /// Represents the sorting order of results (ascending or descending).
+///
+/// The default is ascending, matching the chronological order of launches. Builders only send a
+/// direction that was explicitly set, so the default is never sent on its own.
+///
+/// ```
+/// use rocket_launch_live::{CompanyParamsBuilder, Direction};
+///
+/// let params = CompanyParamsBuilder::new().direction(Direction::Ascending).build();
+///
+/// assert_eq!(params.as_query_string(), "direction=asc");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
+    #[default]
     Ascending,
     Descending,
 }
 
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Ascending => write!(f, "asc"),
+            Direction::Descending => write!(f, "desc"),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = BuilderError;
+
+    /// Parse a direction from `asc` or `desc`, ignoring case.
+    ///
+    /// ```
+    /// use rocket_launch_live::Direction;
+    ///
+    /// assert_eq!("DESC".parse::<Direction>(), Ok(Direction::Descending));
+    /// assert!("down".parse::<Direction>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "asc" => Ok(Direction::Ascending),
+            "desc" => Ok(Direction::Descending),
+            _ => Err(BuilderError::InvalidDirection(s.to_string())),
+        }
+    }
+}
+
 /// Low level text representation of the API parameters sent to the server.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Params(Vec<String>);
 
+impl Params {
+    /// Combine two parameter sets, with the parameters of other replacing any with the same name.
+    ///
+    /// ```
+    /// use rocket_launch_live::LaunchParamsBuilder;
+    ///
+    /// let base = LaunchParamsBuilder::new().country_code("US").limit(10).build();
+    /// let overlay = LaunchParamsBuilder::new().limit(5).build();
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", base.merge(overlay)),
+    ///     r#"Params(["country_code=US", "limit=5"])"#
+    /// );
+    /// ```
+    pub fn merge(self, other: Params) -> Params {
+        let names: Vec<&str> = other.0.iter().map(|param| param_name(param)).collect();
+        let mut params: Vec<String> = self
+            .0
+            .into_iter()
+            .filter(|param| !names.contains(&param_name(param)))
+            .collect();
+        params.extend(other.0);
+
+        Params(params)
+    }
+
+    /// Get the query string sent to the server, with the parameters joined by `&`.
+    ///
+    /// ```
+    /// use rocket_launch_live::LaunchParamsBuilder;
+    ///
+    /// let params = LaunchParamsBuilder::new().country_code("US").limit(5).build();
+    ///
+    /// assert_eq!(params.as_query_string(), "country_code=US&limit=5");
+    /// ```
+    pub fn as_query_string(&self) -> String {
+        self.0.join("&")
+    }
+
+    /// Check if a parameter is set.
+    #[cfg(feature = "ics")]
+    fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|param| param_name(param) == name)
+    }
+
+    /// Get the value of the page parameter, if set.
+    fn page(&self) -> Option<i64> {
+        self.0
+            .iter()
+            .find_map(|param| param.strip_prefix("page="))
+            .and_then(|page| page.parse().ok())
+    }
+
+    /// Copy the parameters, replacing the page parameter.
+    fn with_page(&self, page: i64) -> Params {
+        let mut params: Vec<String> = self
+            .0
+            .iter()
+            .filter(|param| !param.starts_with("page="))
+            .cloned()
+            .collect();
+        params.push(format!("page={}", page));
+
+        Params(params)
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_query_string())
+    }
+}
+
+/// Format a low level `name=value` parameter, with the name and value percent-encoded.
+#[doc(hidden)]
+pub fn param(name: &str, value: impl fmt::Display) -> String {
+    format!(
+        "{}={}",
+        percent_encode(name),
+        percent_encode(&value.to_string())
+    )
+}
+
+/// Percent-encode every byte of the text other than the unreserved characters of RFC 3986.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Get the name part of a low level `name=value` parameter.
+fn param_name(param: &str) -> &str {
+    param.split_once('=').map_or(param, |(name, _)| name)
+}
+
 /// Parameters used by multiple builders by composition.
 #[derive(Default)]
 pub struct CommonParams<'a> {
@@ -95,9 +292,27 @@ pub struct CommonParams<'a> {
     state_abbr: Option<&'a str>,
     country_code: Option<&'a str>,
     slug: Option<&'a str>,
+    limit: Option<i64>,
+    direction: Option<Direction>,
     page: Option<i64>,
 }
 
+impl CommonParams<'_> {
+    /// Check that the country_code, if set, is a known ISO 3166-1 alpha-2 code, and that the
+    /// state_abbr, if set, comes with the US country_code.
+    fn validate(&self) -> Result<(), BuilderError> {
+        match (self.country_code, self.state_abbr) {
+            (Some(code), _) if !country::is_known(code) => {
+                Err(BuilderError::UnknownCountryCode(code.to_string()))
+            }
+            (code, Some(state)) if !code.is_some_and(|code| code.eq_ignore_ascii_case("US")) => {
+                Err(BuilderError::StateWithoutCountry(state.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Builder to generate the API parameters to filter calls to the companies endpoint.
 #[derive(Default)]
 pub struct CompanyParamsBuilder<'a> {
@@ -111,33 +326,7 @@ impl<'a> CompanyParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the company id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
-
-    /// Set the company name paramter.
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.common_params.name = Some(name);
-
-        self
-    }
-
-    /// Set the company country_code parameter.
-    pub fn country_code(&mut self, country_code: &'a str) -> &mut Self {
-        self.common_params.country_code = Some(country_code);
-
-        self
-    }
-
-    /// Set the company slug paramter.
-    pub fn slug(&mut self, slug: &'a str) -> &mut Self {
-        self.common_params.slug = Some(slug);
-
-        self
-    }
+    common_setters!('a, "company", id, name, country_code, slug, limit, direction, page);
 
     /// Set the company inactive parameter.
     pub fn inactive(&mut self, inactive: bool) -> &mut Self {
@@ -146,13 +335,6 @@ impl<'a> CompanyParamsBuilder<'a> {
         self
     }
 
-    /// Set the company page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
-
     /// Build the low level company parameters from all the set parameters.
     pub fn build(&self) -> Params {
         let mut params: Vec<String> = Vec::new();
@@ -162,13 +344,40 @@ impl<'a> CompanyParamsBuilder<'a> {
         add_param!(params, self.common_params.country_code, "country_code");
         add_param!(params, self.common_params.slug, "slug");
         add_param!(params, self.inactive, "inactive");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
+
+    /// Build the low level company parameters, or an error if the country_code isn't known.
+    pub fn try_build(&self) -> Result<Params, BuilderError> {
+        self.common_params.validate()?;
+
+        Ok(self.build())
+    }
 }
 
 /// Builder to generate the API parameters to filter calls to the launches endpoint.
+///
+/// Since build borrows the builder, the setters and build can be chained in a single expression
+/// on a temporary builder, with no mutable binding. A builder bound to a variable can be set
+/// conditionally and built several times.
+///
+/// ```
+/// use rocket_launch_live::LaunchParamsBuilder;
+///
+/// let params = LaunchParamsBuilder::new().country_code("US").limit(5).build();
+/// assert_eq!(format!("{:?}", params), r#"Params(["country_code=US", "limit=5"])"#);
+///
+/// let mut builder = LaunchParamsBuilder::new();
+/// builder.country_code("US");
+/// if true {
+///     builder.limit(5);
+/// }
+/// assert_eq!(format!("{:?}", builder.build()), format!("{:?}", params));
+/// ```
 #[derive(Default)]
 pub struct LaunchParamsBuilder<'a> {
     common_params: CommonParams<'a>,
@@ -182,8 +391,7 @@ pub struct LaunchParamsBuilder<'a> {
     tag_id: Option<i64>,
     vehicle_id: Option<i64>,
     search: Option<&'a str>,
-    limit: Option<i64>,
-    direction: Option<Direction>,
+    invalid_fields: Vec<&'static str>,
 }
 
 impl<'a> LaunchParamsBuilder<'a> {
@@ -193,12 +401,25 @@ impl<'a> LaunchParamsBuilder<'a> {
     }
 
     /// Set the launch id parameter.
+    ///
+    /// The id matches a single launch, so the API ignores any other filter set alongside it.
+    /// Those filters are still sent, but they don't narrow down the result.
+    ///
+    /// ```
+    /// use rocket_launch_live::LaunchParamsBuilder;
+    ///
+    /// let params = LaunchParamsBuilder::new().id(1234).country_code("US").build();
+    ///
+    /// assert_eq!(format!("{:?}", params), r#"Params(["id=1234", "country_code=US"])"#);
+    /// ```
     pub fn id(&mut self, id: i64) -> &mut Self {
         self.common_params.id = Some(id);
 
         self
     }
 
+    common_setters!('a, "launch", state_abbr, country_code, slug, limit, direction, page);
+
     /// Set the launch cospar_id parameter.
     pub fn cospar_id(&mut self, cospar_id: &'a str) -> &mut Self {
         self.cospar_id = Some(cospar_id);
@@ -210,6 +431,7 @@ impl<'a> LaunchParamsBuilder<'a> {
     pub fn after_date(&mut self, after_date: Option<NaiveDate>) -> Result<&mut Self, &'static str> {
         match after_date {
             Some(date) => {
+                self.defer_error("after_date", true);
                 self.after_date = Some(date);
 
                 Ok(self)
@@ -225,6 +447,7 @@ impl<'a> LaunchParamsBuilder<'a> {
     ) -> Result<&mut Self, &'static str> {
         match before_date {
             Some(date) => {
+                self.defer_error("before_date", true);
                 self.before_date = Some(date);
 
                 Ok(self)
@@ -242,6 +465,7 @@ impl<'a> LaunchParamsBuilder<'a> {
         match date {
             Some(date) => match time {
                 Some(time) => {
+                    self.defer_error("modified_since", true);
                     self.modified_since = Some(NaiveDateTime::new(date, time));
 
                     Ok(self)
@@ -252,86 +476,105 @@ impl<'a> LaunchParamsBuilder<'a> {
         }
     }
 
-    /// Set the launch location_id parameter.
-    pub fn location_id(&mut self, location_id: i64) -> &mut Self {
-        self.location_id = Some(location_id);
+    /// Set the launch after_date parameter, deferring a parse error until [`Self::try_build`].
+    pub fn after_date_deferred(&mut self, after_date: Option<NaiveDate>) -> &mut Self {
+        self.defer_error("after_date", after_date.is_some());
+        self.after_date = after_date;
 
         self
     }
 
-    /// Set the launch pad_id parameter.
-    pub fn pad_id(&mut self, pad_id: i64) -> &mut Self {
-        self.pad_id = Some(pad_id);
+    /// Set the launch before_date parameter, deferring a parse error until [`Self::try_build`].
+    pub fn before_date_deferred(&mut self, before_date: Option<NaiveDate>) -> &mut Self {
+        self.defer_error("before_date", before_date.is_some());
+        self.before_date = before_date;
 
         self
     }
 
-    /// Set the launch provider_id parameter.
-    pub fn provider_id(&mut self, provider_id: i64) -> &mut Self {
-        self.provider_id = Some(provider_id);
-
-        self
-    }
+    /// Set the launch modified_since parameter, deferring a parse error until [`Self::try_build`].
+    pub fn modified_since_deferred(
+        &mut self,
+        date: Option<NaiveDate>,
+        time: Option<NaiveTime>,
+    ) -> &mut Self {
+        let modified_since = date
+            .zip(time)
+            .map(|(date, time)| NaiveDateTime::new(date, time));
 
-    /// Set the launch tag_id parameter.
-    pub fn tag_id(&mut self, tag_id: i64) -> &mut Self {
-        self.tag_id = Some(tag_id);
+        self.defer_error("modified_since", modified_since.is_some());
+        self.modified_since = modified_since;
 
         self
     }
 
-    /// Set the launch vehicle_id parameter.
-    pub fn vehicle_id(&mut self, vehicle_id: i64) -> &mut Self {
-        self.vehicle_id = Some(vehicle_id);
+    /// Set the launch modified_since parameter from a date and time (in UTC).
+    pub fn modified_since_dt(&mut self, modified_since: NaiveDateTime) -> &mut Self {
+        self.defer_error("modified_since", true);
+        self.modified_since = Some(modified_since);
 
         self
     }
 
-    /// Set the launch state_abbr parameter.
-    pub fn state_abbr(&mut self, sate_abbr: &'a str) -> &mut Self {
-        self.common_params.state_abbr = Some(sate_abbr);
+    fn defer_error(&mut self, field: &'static str, parsed: bool) {
+        self.invalid_fields.retain(|invalid| *invalid != field);
 
-        self
+        if !parsed {
+            self.invalid_fields.push(field);
+        }
     }
 
-    /// Set the launch country_code parameter.
-    pub fn country_code(&mut self, country_code: &'a str) -> &mut Self {
-        self.common_params.country_code = Some(country_code);
+    /// Set the launch location_id parameter.
+    pub fn location_id(&mut self, location_id: i64) -> &mut Self {
+        self.location_id = Some(location_id);
 
         self
     }
 
-    /// Set the launch search parameter.
-    pub fn search(&mut self, search: &'a str) -> &mut Self {
-        self.search = Some(search);
+    /// Set the launch pad_id parameter.
+    pub fn pad_id(&mut self, pad_id: i64) -> &mut Self {
+        self.pad_id = Some(pad_id);
 
         self
     }
 
-    /// Set the launch slug parameter.
-    pub fn slug(&mut self, slug: &'a str) -> &mut Self {
-        self.common_params.slug = Some(slug);
+    /// Set the launch provider_id parameter.
+    pub fn provider_id(&mut self, provider_id: i64) -> &mut Self {
+        self.provider_id = Some(provider_id);
 
         self
     }
 
-    /// Set the launch limit parameter.
-    pub fn limit(&mut self, limit: i64) -> &mut Self {
-        self.limit = Some(limit);
+    /// Set the launch tag_id parameter.
+    ///
+    /// The API takes a single tag, see [`RocketLaunchLive::launches_by_tags`] to match any of
+    /// several tags.
+    pub fn tag_id(&mut self, tag_id: i64) -> &mut Self {
+        self.tag_id = Some(tag_id);
 
         self
     }
 
-    /// Set the launch direction parameter.
-    pub fn direction(&mut self, direction: Direction) -> &mut Self {
-        self.direction = Some(direction);
+    /// Set the launch vehicle_id parameter.
+    pub fn vehicle_id(&mut self, vehicle_id: i64) -> &mut Self {
+        self.vehicle_id = Some(vehicle_id);
 
         self
     }
 
-    /// Set the launch page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
+    /// Set the launch search parameter.
+    ///
+    /// Like every parameter value, the search text is percent-encoded.
+    ///
+    /// ```
+    /// use rocket_launch_live::LaunchParamsBuilder;
+    ///
+    /// let params = LaunchParamsBuilder::new().search("Falcon & Heavy").build();
+    ///
+    /// assert_eq!(params.as_query_string(), "search=Falcon%20%26%20Heavy");
+    /// ```
+    pub fn search(&mut self, search: &'a str) -> &mut Self {
+        self.search = Some(search);
 
         self
     }
@@ -353,25 +596,72 @@ impl<'a> LaunchParamsBuilder<'a> {
         add_param!(params, self.common_params.country_code, "country_code");
         add_param!(params, self.search, "search");
         add_param!(params, self.common_params.slug, "slug");
-        add_param!(params, self.limit, "limit");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
 
         if let Some(modified_since) = self.modified_since {
-            params.push(format!(
-                "modified_since={}T{}Z",
-                modified_since.date(),
-                modified_since.time()
+            params.push(param(
+                "modified_since",
+                format!("{}T{}Z", modified_since.date(), modified_since.time()),
             ));
         }
 
-        if let Some(direction) = &self.direction {
-            match direction {
-                Direction::Ascending => params.push(String::from("direction=asc")),
-                Direction::Descending => params.push(String::from("direction=desc")),
+        add_param!(params, self.common_params.direction, "direction");
+
+        Params(params)
+    }
+
+    /// Build the low level launch parameters, or list every parameter that failed to parse.
+    ///
+    /// Once every parameter parsed, an after_date later than the before_date is rejected, the
+    /// country_code is checked against the ISO 3166-1 alpha-2 codes and a state_abbr requires the
+    /// US country_code, so that these mistakes are caught before sending a request that can't
+    /// match any launch.
+    ///
+    /// ```
+    /// use rocket_launch_live::{BuilderError, LaunchParamsBuilder, NaiveDate};
+    ///
+    /// let result = LaunchParamsBuilder::new()
+    ///     .after_date_deferred(NaiveDate::parse_from_str("2023-13-01", "%Y-%m-%d").ok())
+    ///     .before_date_deferred(NaiveDate::parse_from_str("tomorrow", "%Y-%m-%d").ok())
+    ///     .try_build();
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BuilderError::InvalidFields(vec!["after_date", "before_date"])
+    /// );
+    ///
+    /// let result = LaunchParamsBuilder::new().country_code("XX").try_build();
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BuilderError::UnknownCountryCode(String::from("XX"))
+    /// );
+    ///
+    /// let result = LaunchParamsBuilder::new().state_abbr("FL").try_build();
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BuilderError::StateWithoutCountry(String::from("FL"))
+    /// );
+    /// ```
+    pub fn try_build(&self) -> Result<Params, BuilderError> {
+        if !self.invalid_fields.is_empty() {
+            return Err(BuilderError::InvalidFields(self.invalid_fields.clone()));
+        }
+
+        if let (Some(after_date), Some(before_date)) = (self.after_date, self.before_date) {
+            if after_date > before_date {
+                return Err(BuilderError::InvertedDateRange {
+                    after_date,
+                    before_date,
+                });
             }
         }
 
-        Params(params)
+        self.common_params.validate()?;
+
+        Ok(self.build())
     }
 }
 
@@ -387,40 +677,7 @@ impl<'a> LocationParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the location id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
-
-    /// Set the location name parameter.
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.common_params.name = Some(name);
-
-        self
-    }
-
-    /// Set the location state_abbr parameter.
-    pub fn state_abbr(&mut self, state_abbr: &'a str) -> &mut Self {
-        self.common_params.state_abbr = Some(state_abbr);
-
-        self
-    }
-
-    /// Set the location country_code parameter.
-    pub fn country_code(&mut self, country_code: &'a str) -> &mut Self {
-        self.common_params.country_code = Some(country_code);
-
-        self
-    }
-
-    /// Set the location page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
+    common_setters!('a, "location", id, name, state_abbr, country_code, limit, direction, page);
 
     /// Build the low level location parameters from all the set parameters.
     pub fn build(&self) -> Params {
@@ -430,10 +687,21 @@ impl<'a> LocationParamsBuilder<'a> {
         add_param!(params, self.common_params.name, "name");
         add_param!(params, self.common_params.state_abbr, "state_abbr");
         add_param!(params, self.common_params.country_code, "country_code");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
+
+    /// Build the low level location parameters, or an error if the country_code isn't known.
+    ///
+    /// US states are the only states, so a state_abbr without the US country_code is an error.
+    pub fn try_build(&self) -> Result<Params, BuilderError> {
+        self.common_params.validate()?;
+
+        Ok(self.build())
+    }
 }
 
 /// Builder to generate the API parameters to filter calls to the missions endpoint.
@@ -448,26 +716,7 @@ impl<'a> MissionParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the mission id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
-
-    /// Set the mission name parameter.
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.common_params.name = Some(name);
-
-        self
-    }
-
-    /// Set the mission page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
+    common_setters!('a, "mission", id, name, limit, direction, page);
 
     /// Build the low level mission parameters from all the set parameters.
     pub fn build(&self) -> Params {
@@ -475,7 +724,9 @@ impl<'a> MissionParamsBuilder<'a> {
 
         add_param!(params, self.common_params.id, "id");
         add_param!(params, self.common_params.name, "name");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
@@ -493,40 +744,7 @@ impl<'a> PadParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the pad id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
-
-    /// Set the pad name parameter.
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.common_params.name = Some(name);
-
-        self
-    }
-
-    /// Set the pad state_abbr parameter.
-    pub fn state_abbr(&mut self, state_abbr: &'a str) -> &mut Self {
-        self.common_params.state_abbr = Some(state_abbr);
-
-        self
-    }
-
-    /// Set the pad country_code parameter.
-    pub fn country_code(&mut self, country_code: &'a str) -> &mut Self {
-        self.common_params.country_code = Some(country_code);
-
-        self
-    }
-
-    /// Set the pad page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
+    common_setters!('a, "pad", id, name, state_abbr, country_code, limit, direction, page);
 
     /// Build the low level pad parameters from all the set parameters.
     pub fn build(&self) -> Params {
@@ -536,10 +754,21 @@ impl<'a> PadParamsBuilder<'a> {
         add_param!(params, self.common_params.name, "name");
         add_param!(params, self.common_params.state_abbr, "state_abbr");
         add_param!(params, self.common_params.country_code, "country_code");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
+
+    /// Build the low level pad parameters, or an error if the country_code isn't known.
+    ///
+    /// US states are the only states, so a state_abbr without the US country_code is an error.
+    pub fn try_build(&self) -> Result<Params, BuilderError> {
+        self.common_params.validate()?;
+
+        Ok(self.build())
+    }
 }
 
 /// Builder to generate the API parameters to filter calls to the tags endpoint.
@@ -555,12 +784,7 @@ impl<'a> TagParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the tag id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
+    common_setters!('a, "tag", id, limit, direction, page);
 
     /// Set the tag text parameter.
     pub fn text(&mut self, text: &'a str) -> &mut Self {
@@ -569,20 +793,15 @@ impl<'a> TagParamsBuilder<'a> {
         self
     }
 
-    /// Set the tag page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
-
     /// Build the low level tag parameters from all the set parameters.
     pub fn build(&self) -> Params {
         let mut params = Vec::new();
 
         add_param!(params, self.common_params.id, "id");
         add_param!(params, self.text, "text");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
@@ -600,26 +819,7 @@ impl<'a> VehicleParamsBuilder<'a> {
         Self::default()
     }
 
-    /// Set the vehicle id parameter.
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.common_params.id = Some(id);
-
-        self
-    }
-
-    /// Set the vehicle name parameter.
-    pub fn name(&mut self, name: &'a str) -> &mut Self {
-        self.common_params.name = Some(name);
-
-        self
-    }
-
-    /// Set the vehicle page parameter.
-    pub fn page(&mut self, page: i64) -> &mut Self {
-        self.common_params.page = Some(page);
-
-        self
-    }
+    common_setters!('a, "vehicle", id, name, limit, direction, page);
 
     /// Build the low level vehicle parameters from all the set parameters.
     pub fn build(&self) -> Params {
@@ -627,102 +827,1889 @@ impl<'a> VehicleParamsBuilder<'a> {
 
         add_param!(params, self.common_params.id, "id");
         add_param!(params, self.common_params.name, "name");
+        add_param!(params, self.common_params.limit, "limit");
         add_param!(params, self.common_params.page, "page");
+        add_param!(params, self.common_params.direction, "direction");
 
         Params(params)
     }
 }
 
+/// Parse a response body, turning the error messages sent by the API into [`RllError::Api`].
+///
+/// Besides the errors field of the standard response, bodies with other shapes like
+/// `{"error": "..."}` or `{"message": "..."}` are recognized as errors too.
+fn parse_response<T: DeserializeOwned>(body: &str) -> Result<Response<T>, RllError> {
+    match serde_json::from_str::<Response<T>>(body) {
+        Ok(resp) => match &resp.errors {
+            Some(errors) if !errors.is_empty() => Err(RllError::Api(errors.join(" "))),
+            // Missing fields are defaulted, so an error body without results parses as well.
+            _ if resp.result.is_empty() => match error_message(body) {
+                Some(message) => Err(RllError::Api(message)),
+                None => Ok(resp),
+            },
+            _ => Ok(resp),
+        },
+        Err(e) => Err(error_message(body).map_or(RllError::Json(e), RllError::Api)),
+    }
+}
+
+/// Parse the body of a response received with a status, see [`parse_response`].
+///
+/// A body that can't be parsed is an [`RllError::UnexpectedResponse`], with the status and the
+/// start of the body, such as an HTML error page.
+fn parse_status_response<T: DeserializeOwned>(
+    status: reqwest::StatusCode,
+    body: &str,
+) -> Result<Response<T>, RllError> {
+    parse_response(body).map_err(|e| match e {
+        RllError::Json(error) => {
+            let mut snippet: String = body.chars().take(BODY_SNIPPET_LEN).collect();
+
+            if snippet.len() < body.len() {
+                snippet.push_str("...");
+            }
+
+            RllError::UnexpectedResponse {
+                status,
+                body: snippet,
+                error,
+            }
+        }
+        e => e,
+    })
+}
+
+/// Find the error messages of a JSON body that isn't a standard response.
+fn error_message(body: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    let messages: Vec<&str> = ["errors", "error", "message"]
+        .iter()
+        .filter_map(|field| value.get(field))
+        .flat_map(|value| match value {
+            serde_json::Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        })
+        .filter_map(|value| value.as_str().or_else(|| value.get("message")?.as_str()))
+        .collect();
+
+    (!messages.is_empty()).then(|| messages.join(" "))
+}
+
+/// Check that a base URL is an absolute HTTP(S) URL, and remove any trailing slash.
+fn base_url(url: &str) -> Result<&str, RllError> {
+    let url = url.trim_end_matches('/');
+
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(url),
+        _ => Err(RllError::InvalidUrl(url.to_string())),
+    }
+}
+
+/// Read the rate limit state from the X-RateLimit-Remaining and X-RateLimit-Reset headers.
+///
+/// The reset header is read as a Unix timestamp, or as a number of seconds from now when it's too
+/// small to be a timestamp.
+fn rate_limit(headers: &reqwest::header::HeaderMap) -> Option<RateLimit> {
+    let header =
+        |name: &str| -> Option<i64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+    let remaining = header("x-ratelimit-remaining")?;
+    let reset = header("x-ratelimit-reset")?;
+    let reset_at = if reset >= 1_000_000_000 {
+        DateTime::from_timestamp(reset, 0)?
+    } else {
+        Utc::now() + chrono::Duration::seconds(reset)
+    };
+
+    Some(RateLimit {
+        remaining,
+        reset_at,
+    })
+}
+
+/// Path names of the endpoints supported by the API client.
+const ENDPOINTS: [&str; 7] = [
+    "companies",
+    "launches",
+    "locations",
+    "missions",
+    "pads",
+    "tags",
+    "vehicles",
+];
+
+/// Maximum number of characters of a body kept in an [`RllError::UnexpectedResponse`].
+const BODY_SNIPPET_LEN: usize = 512;
+
+/// User-Agent header sent by default, with the name and version of this crate.
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
 /// API client containing all the public endpoint methods.
-pub struct RocketLaunchLive<'a> {
-    key: &'a str,
-    url: &'a str,
+///
+/// Cloning the client is cheap, and the clones share the same connection pool.
+#[derive(Clone)]
+pub struct RocketLaunchLive {
+    key: String,
+    url: String,
+    client: reqwest::Client,
+    accept_language: Option<String>,
+    user_agent: String,
+    local_address: Option<IpAddr>,
+    proxy: Option<reqwest::Proxy>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    batch_size: usize,
+    concurrency: usize,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    #[cfg(feature = "vcr")]
+    cassette: Option<std::sync::Arc<vcr::Cassette>>,
 }
 
-impl<'a> RocketLaunchLive<'a> {
+impl RocketLaunchLive {
     /// Create a new API client with an API key.
-    pub fn new(key: &'a str) -> Self {
+    pub fn new(key: impl Into<String>) -> Self {
         Self {
-            key,
-            url: "https://fdo.rocketlaunch.live",
+            key: key.into(),
+            url: String::from("https://fdo.rocketlaunch.live"),
+            client: reqwest::Client::new(),
+            accept_language: None,
+            user_agent: String::from(USER_AGENT),
+            local_address: None,
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            connect_timeout: None,
+            batch_size: 50,
+            concurrency: 4,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
 
-    async fn request<T: DeserializeOwned>(
-        &self,
-        endpoint: &'a str,
-        params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
-        let client = reqwest::Client::new();
-        let resp: Response<T> = client
-            .get(format!(
-                "{}/json/{}?{}",
-                self.url,
-                endpoint,
-                params.unwrap_or_default().0.join("&")
-            ))
-            .header("Authorization", format!("Bearer {}", self.key))
-            .send()
-            .await?
-            .json()
-            .await?;
+    /// Create a new API client with an API key, or an error if the key is empty.
+    pub fn try_new(key: impl Into<String>) -> Result<Self, RllError> {
+        let key = key.into();
 
-        Ok(resp)
+        if key.trim().is_empty() {
+            return Err(RllError::EmptyApiKey);
+        }
+
+        Ok(Self::new(key))
     }
 
-    /// Retrieve all companies in the database (optionally filtered by params) or an error.
-    pub async fn companies<T: DeserializeOwned>(
-        &self,
-        params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
-        self.request("companies", params).await
+    /// Create a new API client with the API key read from the RLL_API_KEY environment variable.
+    ///
+    /// It's an error if the variable is unset or empty.
+    pub fn from_env() -> Result<Self, RllError> {
+        let key = std::env::var("RLL_API_KEY").map_err(|_| RllError::MissingApiKey)?;
+
+        Self::try_new(key)
     }
 
-    /// Retrieve all launches in the database (optionally filtered by params) or an error.
-    pub async fn launches<T: DeserializeOwned>(
-        &self,
-        params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
-        self.request("launches", params).await
+    /// Create a new API client with an API key, sending the requests through an HTTP client.
+    ///
+    /// The HTTP client is reused by every request, so its connection pool, proxy and other
+    /// settings apply to all of them. Setting any of the connection options of this client, such
+    /// as [`Self::local_address`], replaces it with a new HTTP client built with those options.
+    pub fn with_client(key: impl Into<String>, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            ..Self::new(key)
+        }
     }
 
-    /// Retrieve all locations in the database (optionally filtered by params) or an error.
-    pub async fn locations<T: DeserializeOwned>(
-        &self,
-        params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
-        self.request("locations", params).await
+    /// Create a new API client with an API key, sending the requests to another base URL.
+    ///
+    /// This is meant for mock servers and gateways. The URL must be an absolute HTTP(S) URL, and
+    /// any trailing slash is removed.
+    ///
+    /// ```
+    /// use rocket_launch_live::RocketLaunchLive;
+    ///
+    /// assert!(RocketLaunchLive::with_base_url("key", "http://localhost:8080/").is_ok());
+    /// assert!(RocketLaunchLive::with_base_url("key", "localhost:8080").is_err());
+    /// ```
+    pub fn with_base_url(key: impl Into<String>, url: &str) -> Result<Self, RllError> {
+        Ok(Self {
+            url: base_url(url)?.to_string(),
+            ..Self::new(key)
+        })
+    }
+
+    /// Set the Accept-Language header sent with every request (unset uses the server default).
+    pub fn accept_language(mut self, language: impl Into<String>) -> Self {
+        self.accept_language = Some(language.into());
+
+        self
     }
 
-    /// Retrieve all missions in the database (optionally filtered by params) or an error.
+    /// Set the User-Agent header sent with every request (`rocket_launch_live/<version>` by
+    /// default), e.g. to identify the application to the API provider.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+
+        self
+    }
+
+    /// Bind the outgoing requests to a local address (unset lets the system choose), or an error.
+    ///
+    /// This relies on the local address support of reqwest, which isn't available on wasm.
+    pub fn local_address(mut self, address: IpAddr) -> Result<Self, RllError> {
+        self.local_address = Some(address);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Send every request through an HTTP(S) proxy, or an error if the proxy URL is invalid.
+    ///
+    /// Without a proxy set here, the proxy set by the HTTP_PROXY and HTTPS_PROXY environment
+    /// variables, if any, is used. Failing to connect through the proxy is an [`RllError::Http`].
+    pub fn with_proxy(mut self, url: &str) -> Result<Self, RllError> {
+        let proxy = reqwest::Proxy::all(url).map_err(|_| RllError::InvalidUrl(url.to_string()))?;
+        self.proxy = Some(proxy);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Set the maximum number of idle connections kept open (unlimited by default), or an error.
+    ///
+    /// The helpers that fetch several records have at most as many requests in flight as their
+    /// [`Self::batching`] concurrency, so keeping that many idle connections is usually enough.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Result<Self, RllError> {
+        self.pool_max_idle_per_host = Some(max);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Set how long an idle connection is kept open (90 seconds by default), or an error.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Result<Self, RllError> {
+        self.pool_idle_timeout = Some(timeout);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Set the time limit of a whole request, from connecting to reading the body (none by default),
+    /// or an error.
+    ///
+    /// A request that takes longer fails with [`RllError::Timeout`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Result<Self, RllError> {
+        self.timeout = Some(timeout);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Set the time limit to connect to the server (none by default), or an error.
+    ///
+    /// A connection that takes longer fails with [`RllError::Timeout`].
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Result<Self, RllError> {
+        self.connect_timeout = Some(timeout);
+        self.client = self.http_client()?;
+
+        Ok(self)
+    }
+
+    /// Set how the helpers that fetch several records spread their requests (50 and 4 by default).
+    ///
+    /// The records are fetched in batches of batch_size, one batch after the other, with at most
+    /// concurrency requests of a batch in flight at the same time.
+    pub fn batching(mut self, batch_size: usize, concurrency: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self.concurrency = concurrency.max(1);
+
+        self
+    }
+
+    /// Retry failed GET requests up to max times, with exponential backoff (no retries by default).
+    ///
+    /// Only network errors and 5xx responses are retried. The n-th retry waits base_delay * 2^(n-1)
+    /// plus a random jitter of up to base_delay. When a request still fails after retrying, the
+    /// error is [`RllError::Retries`], holding the number of attempts made and the last error.
+    pub fn with_retries(mut self, max: u32, base_delay: Duration) -> Self {
+        self.max_retries = max;
+        self.retry_base_delay = base_delay;
+
+        self
+    }
+
+    /// Record the API responses to a cassette file, or replay them if they were already recorded.
+    ///
+    /// Requests found in the cassette are answered from it without touching the network, while
+    /// the others are sent to the API and their responses added to the file. The API key is
+    /// never written to the cassette.
+    #[cfg(feature = "vcr")]
+    pub fn cassette(mut self, path: impl Into<std::path::PathBuf>) -> Result<Self, RllError> {
+        self.cassette = Some(std::sync::Arc::new(vcr::Cassette::open(path.into())?));
+
+        Ok(self)
+    }
+
+    /// Get the path names of the endpoints supported by the client (companies, launches, ...).
+    pub fn endpoints() -> &'static [&'static str] {
+        &ENDPOINTS
+    }
+
+    /// Build an HTTP client with the connection options set on this client, or an error.
+    ///
+    /// Building fails when the options can't be applied, e.g. when the TLS backend can't be set
+    /// up, which is an [`RllError::Http`]. The setters return it instead of keeping the client.
+    fn http_client(&self) -> Result<reqwest::Client, RllError> {
+        let mut builder = reqwest::Client::builder().local_address(self.local_address);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.send(endpoint, params, None::<&()>).await
+    }
+
+    /// Send a GET request to any endpoint, with the client authorization and deserialization.
+    ///
+    /// The endpoint is appended to the base URL after `/json/`, so `get("launches", None)` is the
+    /// same request as [`Self::launches`]. This is meant for endpoints not wrapped by the client.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request(endpoint, params).await
+    }
+
+    /// Send a GET request to any endpoint, skipping the records of the result that can't be parsed.
+    ///
+    /// The records left out are kept as raw JSON in [`Response::skipped`], so that a single
+    /// malformed record doesn't fail the whole request.
+    pub async fn get_lenient<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        Ok(self
+            .request::<serde_json::Value>(endpoint, params)
+            .await?
+            .into_lenient())
+    }
+
+    /// Send a POST request with a JSON body to an endpoint, with the client authorization.
+    ///
+    /// All the current API endpoints are queried with GET requests, through the other methods.
+    /// This is meant for endpoints that take a richer filter as a JSON body.
+    pub async fn request_post<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+        body: &B,
+    ) -> Result<Response<T>, RllError> {
+        self.send(endpoint, params, Some(body)).await
+    }
+
+    /// Stream the raw body of a GET request to any endpoint, without parsing it.
+    ///
+    /// The URL and headers are the same as [`Self::get`], but the body is passed on chunk by
+    /// chunk as it arrives, e.g. to write large exports straight to disk. Responses that aren't
+    /// successful are an error. The request is neither retried nor recorded by a cassette.
+    pub fn stream_raw(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> impl Stream<Item = Result<Bytes, RllError>> {
+        let req = self.authorize(self.client.get(self.endpoint_url(endpoint, params)));
+
+        futures::stream::once(async move { req.send().await?.error_for_status() })
+            .map_ok(|resp| resp.bytes_stream().map_err(RllError::from))
+            .map_err(RllError::from)
+            .try_flatten()
+    }
+
+    /// Get the full URL of an endpoint with the params as query string.
+    fn endpoint_url(&self, endpoint: &str, params: Option<Params>) -> String {
+        format!(
+            "{}/json/{}?{}",
+            self.url,
+            endpoint,
+            params.unwrap_or_default()
+        )
+    }
+
+    /// Add the authorization, user agent and language headers to a request.
+    fn authorize(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        let req = req
+            .header("Authorization", format!("Bearer {}", self.key))
+            .header("User-Agent", &self.user_agent);
+
+        match &self.accept_language {
+            Some(language) => req.header("Accept-Language", language),
+            None => req,
+        }
+    }
+
+    /// Send a GET request, retried as set by [`Self::with_retries`], or a POST request.
+    async fn send<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+        body: Option<&B>,
+    ) -> Result<Response<T>, RllError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "request",
+            endpoint,
+            params = params.as_ref().map_or(0, |params| params.0.len())
+        );
+        let result = self.send_retried(endpoint, params, body);
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span);
+        let result = result.await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::warn!(error = ?e, "request failed");
+        }
+
+        result
+    }
+
+    async fn send_retried<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+        body: Option<&B>,
+    ) -> Result<Response<T>, RllError> {
+        let mut attempts = 1;
+
+        loop {
+            match self.send_once(endpoint, params.clone(), body).await {
+                Err(e) if body.is_none() && e.is_transient() && attempts <= self.max_retries => {
+                    tokio::time::sleep(self.retry_delay(attempts)).await;
+                    attempts += 1;
+                }
+                Err(e) if attempts > 1 => {
+                    return Err(RllError::Retries {
+                        attempts,
+                        error: Box::new(e),
+                    })
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Get the delay before a retry, doubling with each attempt, plus a random jitter.
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .retry_base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1));
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+
+        backoff.saturating_add(self.retry_base_delay.mul_f64(jitter as f64 / 1000.0))
+    }
+
+    /// Send a single GET request, or a POST request when there's a JSON body to send.
+    async fn send_once<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+        body: Option<&B>,
+    ) -> Result<Response<T>, RllError> {
+        let url = self.endpoint_url(endpoint, params);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url = %url, "sending request");
+
+        #[cfg(feature = "vcr")]
+        let interaction = match body {
+            Some(body) => format!("POST {} {}", url, serde_json::to_string(body)?),
+            None => format!("GET {}", url),
+        };
+
+        #[cfg(feature = "vcr")]
+        if let Some(recorded) = self
+            .cassette
+            .as_ref()
+            .and_then(|cassette| cassette.replay(&interaction))
+        {
+            return parse_response(&recorded);
+        }
+
+        let req = self.authorize(match body {
+            Some(body) => self.client.post(url).json(body),
+            None => self.client.get(url),
+        });
+
+        #[cfg(any(feature = "metrics", feature = "tracing"))]
+        let start = std::time::Instant::now();
+        let result = req.send().await;
+
+        #[cfg(feature = "tracing")]
+        if let Ok(resp) = &result {
+            tracing::info!(
+                status = resp.status().as_u16(),
+                elapsed = ?start.elapsed(),
+                "response received"
+            );
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            let status = match &result {
+                Ok(resp) => resp.status().as_u16().to_string(),
+                Err(_) => String::from("error"),
+            };
+
+            metrics::counter!(
+                "rocket_launch_live_requests_total",
+                "endpoint" => endpoint.to_string(),
+                "status" => status
+            )
+            .increment(1);
+            metrics::histogram!(
+                "rocket_launch_live_request_duration_seconds",
+                "endpoint" => endpoint.to_string()
+            )
+            .record(start.elapsed());
+        }
+
+        let resp = result?;
+
+        if resp.status().is_server_error() {
+            resp.error_for_status_ref()?;
+        }
+
+        let rate_limit = rate_limit(resp.headers());
+        let status = resp.status();
+        let text = resp.text().await?;
+
+        #[cfg(feature = "vcr")]
+        if let Some(cassette) = &self.cassette {
+            cassette.record(&interaction, &text, &self.key)?;
+        }
+
+        let mut resp = parse_status_response(status, &text)?;
+        resp.rate_limit = rate_limit;
+
+        Ok(resp)
+    }
+
+    /// Stream the pages of an endpoint, from the page set in params (or the first) to the last.
+    fn pages<'s, T: DeserializeOwned + 's>(
+        &'s self,
+        endpoint: &'s str,
+        params: Option<Params>,
+    ) -> impl Stream<Item = Result<Response<T>, RllError>> + 's {
+        let params = params.unwrap_or_default();
+        let first_page = params.page().unwrap_or(1);
+
+        futures::stream::try_unfold(Some(first_page), move |page| {
+            let page_params = page.map(|page| (page, params.with_page(page)));
+
+            async move {
+                let Some((page, params)) = page_params else {
+                    return Ok(None);
+                };
+                let resp: Response<T> = self.request(endpoint, Some(params)).await?;
+
+                if resp.result.is_empty() {
+                    return Ok(None);
+                }
+
+                let next_page = resp.next_page(page);
+
+                Ok(Some((resp, next_page)))
+            }
+        })
+    }
+
+    /// Stream the records of an endpoint (optionally filtered by params), one page at a time.
+    ///
+    /// Pages are only requested as the stream is polled, so dropping it or taking a few records
+    /// stops any further requests. The stream starts at the page set in params (or the first page)
+    /// and ends after the last page.
+    pub fn paginate<'s, T: DeserializeOwned + 's>(
+        &'s self,
+        endpoint: &'s str,
+        params: Option<Params>,
+    ) -> impl Stream<Item = Result<T, RllError>> + 's {
+        self.pages(endpoint, params)
+            .map_ok(|resp| futures::stream::iter(resp.result.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Fetch the pages of an endpoint (optionally filtered by params) one at a time.
+    ///
+    /// The fetcher holds a clone of this client, and prefetches the next page while the current
+    /// one is used. See [`PagedFetcher`].
+    pub fn paged<T: DeserializeOwned + Send + 'static>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> PagedFetcher<T> {
+        PagedFetcher::new(self.clone(), endpoint, params)
+    }
+
+    async fn request_all<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        self.pages(endpoint, params)
+            .try_fold(Vec::new(), |mut result, resp| async move {
+                result.extend(resp.result);
+
+                Ok(result)
+            })
+            .await
+    }
+
+    async fn request_all_concurrent<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Params,
+        concurrency: usize,
+    ) -> Result<Vec<T>, RllError> {
+        let first: Response<T> = self.request(endpoint, Some(params.clone())).await?;
+        let last_page = match first.metadata().page_count() {
+            Some(last_page) if !first.result.is_empty() => last_page,
+            _ => return Ok(first.result),
+        };
+        let mut result = first.result;
+        let mut pages = futures::stream::iter(2..=last_page)
+            .map(|page| self.request::<T>(endpoint, Some(params.with_page(page))))
+            .buffered(concurrency.max(1));
+
+        while let Some(resp) = pages.next().await {
+            result.extend(resp?.result);
+        }
+
+        Ok(result)
+    }
+
+    async fn request_count(&self, endpoint: &str, params: Option<Params>) -> Result<i64, RllError> {
+        let params = params
+            .unwrap_or_default()
+            .merge(Params(vec![String::from("limit=1")]));
+        let resp: Response<serde_json::Value> = self.request(endpoint, Some(params)).await?;
+
+        resp.total.ok_or(RllError::MissingTotal)
+    }
+
+    async fn request_by_id<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        id: i64,
+    ) -> Result<Option<T>, RllError> {
+        let resp: Response<T> = self
+            .request(endpoint, Some(Params(vec![format!("id={}", id)])))
+            .await?;
+
+        Ok(resp.result.into_iter().next())
+    }
+
+    async fn request_first<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Params,
+    ) -> Result<Option<T>, RllError> {
+        let params = params.merge(Params(vec![String::from("limit=1")]));
+        let resp: Response<T> = self.request(endpoint, Some(params)).await?;
+
+        Ok(resp.result.into_iter().next())
+    }
+
+    async fn request_by_ids<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        ids: impl IntoIterator<Item = i64>,
+    ) -> Result<Vec<T>, RllError> {
+        let ids: Vec<i64> = ids.into_iter().collect();
+        let mut result = Vec::with_capacity(ids.len());
+
+        for batch in ids.chunks(self.batch_size) {
+            let responses: Vec<Response<T>> = futures::stream::iter(batch)
+                .map(|id| self.request(endpoint, Some(Params(vec![format!("id={}", id)]))))
+                .buffered(self.concurrency)
+                .try_collect()
+                .await?;
+
+            result.extend(responses.into_iter().flat_map(|resp| resp.result));
+        }
+
+        Ok(result)
+    }
+
+    /// Check if a record with the slug exists in an endpoint, or an error.
+    ///
+    /// Only the companies and launches endpoints support slug lookups, any other endpoint is an
+    /// error. A single record is requested, to transfer as little data as possible.
+    pub async fn slug_exists(&self, endpoint: &str, slug: &str) -> Result<bool, RllError> {
+        let params = match endpoint {
+            "companies" => CompanyParamsBuilder::new().slug(slug).build(),
+            "launches" => LaunchParamsBuilder::new().slug(slug).build(),
+            _ => {
+                let message = format!("The {} endpoint doesn't support slugs.", endpoint);
+
+                return Err(RllError::Unsupported(message));
+            }
+        };
+        let params = params.merge(Params(vec![String::from("limit=1")]));
+        let resp: Response<serde_json::Value> = self.request(endpoint, Some(params)).await?;
+
+        Ok(!resp.result.is_empty())
+    }
+
+    /// Search the launches, missions, vehicles and companies matching a term, concurrently.
+    ///
+    /// Launches are matched with the search parameter, and the other records by name. Only the
+    /// first page of each endpoint is requested. An endpoint that fails doesn't fail the search,
+    /// its error is kept in [`SearchResults::errors`] alongside the records of the others.
+    pub async fn search_all(&self, term: &str) -> SearchResults {
+        let (launches, missions, vehicles, companies) = futures::join!(
+            self.launches::<Launch>(Some(LaunchParamsBuilder::new().search(term).build())),
+            self.missions::<Mission>(Some(MissionParamsBuilder::new().name(term).build())),
+            self.vehicles::<Vehicle>(Some(VehicleParamsBuilder::new().name(term).build())),
+            self.companies::<Company>(Some(CompanyParamsBuilder::new().name(term).build())),
+        );
+        let mut results = SearchResults::default();
+
+        match launches {
+            Ok(resp) => results.launches = resp.result,
+            Err(e) => results.errors.push(("launches", e)),
+        }
+
+        match missions {
+            Ok(resp) => results.missions = resp.result,
+            Err(e) => results.errors.push(("missions", e)),
+        }
+
+        match vehicles {
+            Ok(resp) => results.vehicles = resp.result,
+            Err(e) => results.errors.push(("vehicles", e)),
+        }
+
+        match companies {
+            Ok(resp) => results.companies = resp.result,
+            Err(e) => results.errors.push(("companies", e)),
+        }
+
+        results
+    }
+
+    /// Retrieve all companies in the database (optionally filtered by params) or an error.
+    pub async fn companies<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("companies", params).await
+    }
+
+    /// Retrieve every page of companies (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn companies_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("companies", Some(params)).await
+    }
+
+    /// Retrieve the company with the given id, None if there's no such company, or an error.
+    pub async fn company(&self, id: i64) -> Result<Option<Company>, RllError> {
+        self.request_by_id("companies", id).await
+    }
+
+    /// Retrieve the company with the given slug, None if there's no such company, or an error.
+    pub async fn company_by_slug(&self, slug: &str) -> Result<Option<Company>, RllError> {
+        let params = CompanyParamsBuilder::new().slug(slug).build();
+
+        self.request_first("companies", params).await
+    }
+
+    /// Count the companies in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_companies(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("companies", params).await
+    }
+
+    /// Count the launches conducted by a company, None if there's no such company, or an error.
+    ///
+    /// Launch providers are companies, so the company id is used as the provider_id launch filter.
+    /// Launches of vehicles built by the company but provided by another company aren't counted.
+    /// This takes two requests, one to check the company and one to count its launches.
+    pub async fn company_launch_count(&self, company_id: i64) -> Result<Option<i64>, RllError> {
+        if self.company(company_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let params = LaunchParamsBuilder::new().provider_id(company_id).build();
+
+        Ok(Some(self.request_count("launches", Some(params)).await?))
+    }
+
+    /// Retrieve all launches in the database (optionally filtered by params) or an error.
+    pub async fn launches<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("launches", params).await
+    }
+
+    /// Retrieve every page of launches (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn launches_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("launches", Some(params)).await
+    }
+
+    /// Retrieve all pages of launches (optionally filtered by params) as one list, or an error.
+    ///
+    /// The first page is requested alone to learn the number of pages, then the other pages are
+    /// requested with at most concurrency requests in flight. The launches keep the page order.
+    pub async fn launches_all_concurrent<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+        concurrency: usize,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all_concurrent("launches", params, concurrency)
+            .await
+    }
+
+    /// Retrieve launches (optionally filtered by params) as lightweight summaries, or an error.
+    ///
+    /// Only the fields of [`LaunchSummary`] are parsed, which is much cheaper for long lists.
+    pub async fn launches_summary(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<LaunchSummary>, RllError> {
+        self.request("launches", params).await
+    }
+
+    /// Retrieve the launch with the given id, None if there's no such launch, or an error.
+    pub async fn launch(&self, id: i64) -> Result<Option<Launch>, RllError> {
+        self.request_by_id("launches", id).await
+    }
+
+    /// Retrieve the launch with the given slug, None if there's no such launch, or an error.
+    pub async fn launch_by_slug(&self, slug: &str) -> Result<Option<Launch>, RllError> {
+        let params = LaunchParamsBuilder::new().slug(slug).build();
+
+        self.request_first("launches", params).await
+    }
+
+    /// Count the launches in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_launches(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("launches", params).await
+    }
+
+    /// Retrieve the launches with the given ids, in the same order, or an error.
+    ///
+    /// Each id takes one request, spread according to [`Self::batching`]. Ids that don't match any
+    /// launch are left out of the result.
+    pub async fn launches_by_ids(&self, ids: &[i64]) -> Result<Vec<Launch>, RllError> {
+        self.request_by_ids("launches", ids.iter().copied()).await
+    }
+
+    /// Retrieve all launches by the launch provider with the given name, or an error.
+    ///
+    /// The name is first resolved to a company id through the companies endpoint, ignoring case.
+    /// A company whose name is an exact match wins over partial matches, otherwise the name must
+    /// match a single company. Unknown and ambiguous names are errors.
+    pub async fn launches_by_provider(&self, provider: &str) -> Result<Vec<Launch>, RllError> {
+        let params = CompanyParamsBuilder::new().name(provider).build();
+        let companies: Vec<Company> = self.companies_all(Some(params)).await?;
+        let exact: Vec<&Company> = companies
+            .iter()
+            .filter(|company| company.name.trim().eq_ignore_ascii_case(provider.trim()))
+            .collect();
+        let matches = if exact.is_empty() {
+            companies.iter().collect()
+        } else {
+            exact
+        };
+
+        let provider_id = match matches.as_slice() {
+            [company] => company
+                .id
+                .ok_or_else(|| RllError::UnknownProvider(provider.to_string()))?,
+            [] => return Err(RllError::UnknownProvider(provider.to_string())),
+            _ => {
+                return Err(RllError::AmbiguousProvider {
+                    name: provider.to_string(),
+                    matches: matches.iter().map(|company| company.name.clone()).collect(),
+                })
+            }
+        };
+        let params = LaunchParamsBuilder::new().provider_id(provider_id).build();
+
+        self.launches_all(Some(params)).await
+    }
+
+    /// Retrieve all launches with any of the given tags (optionally filtered by params), or an
+    /// error.
+    ///
+    /// The API filters on a single tag, so all pages are requested for each tag in turn, with the
+    /// tag_id of params replaced. The launches are merged in tag order, keeping the first copy of
+    /// a launch with several of the tags.
+    pub async fn launches_by_tags(
+        &self,
+        tag_ids: &[i64],
+        params: Option<Params>,
+    ) -> Result<Vec<Launch>, RllError> {
+        let params = params.unwrap_or_default();
+        let mut launches = Vec::new();
+
+        for tag_id in tag_ids {
+            let tag_params = LaunchParamsBuilder::new().tag_id(*tag_id).build();
+            let tag_launches: Vec<Launch> = self
+                .launches_all(Some(params.clone().merge(tag_params)))
+                .await?;
+            launches.extend(tag_launches);
+        }
+
+        Ok(dedup_by_id(launches))
+    }
+
+    /// Check if the provider of a launch also built its vehicle, None if unknown, or an error.
+    ///
+    /// The provider id is compared with the company_id of the vehicle, so a request is only sent
+    /// to look up the vehicle when the launch doesn't include its company_id. None is returned
+    /// when the provider or the company of the vehicle can't be resolved.
+    pub async fn is_vertically_integrated(
+        &self,
+        launch: &Launch,
+    ) -> Result<Option<bool>, RllError> {
+        let Some(provider_id) = launch.provider.id else {
+            return Ok(None);
+        };
+        let company_id = match (launch.vehicle.company_id, launch.vehicle.id) {
+            (Some(company_id), _) => Some(company_id),
+            (None, Some(vehicle_id)) => self
+                .vehicle(vehicle_id)
+                .await?
+                .and_then(|vehicle| vehicle.company_id),
+            (None, None) => None,
+        };
+
+        Ok(company_id.map(|company_id| company_id == provider_id))
+    }
+
+    /// Retrieve one page of launches (filtered by params) with their full related records.
+    ///
+    /// Besides the launches, each distinct provider, vehicle and location of the page is fetched
+    /// once, so this sends 1 + (distinct related records) requests, spread according to
+    /// [`Self::batching`]. Related records that can't be found are left as None.
+    ///
+    /// The related lookups are capped by max_lookups. When the page needs more, nothing else is
+    /// requested and a [`RllError::TooManyLookups`] error is returned, so lower the page limit.
+    pub async fn launches_enriched(
+        &self,
+        params: Option<Params>,
+        max_lookups: usize,
+    ) -> Result<Vec<EnrichedLaunch>, RllError> {
+        let resp: Response<Launch> = self.request("launches", params).await?;
+        let launches = &resp.result;
+        let provider_ids: BTreeSet<i64> = launches.iter().filter_map(|l| l.provider.id).collect();
+        let vehicle_ids: BTreeSet<i64> = launches.iter().filter_map(|l| l.vehicle.id).collect();
+        let location_ids: BTreeSet<i64> =
+            launches.iter().filter_map(|l| l.pad.location.id).collect();
+        let needed = provider_ids.len() + vehicle_ids.len() + location_ids.len();
+
+        if needed > max_lookups {
+            return Err(RllError::TooManyLookups {
+                needed,
+                max: max_lookups,
+            });
+        }
+
+        let (providers, vehicles, locations) = futures::try_join!(
+            self.request_by_ids::<Company>("companies", provider_ids),
+            self.request_by_ids::<Vehicle>("vehicles", vehicle_ids),
+            self.request_by_ids::<Location>("locations", location_ids),
+        )?;
+        let providers = api_models::index_by_id(providers);
+        let vehicles = api_models::index_by_id(vehicles);
+        let locations = api_models::index_by_id(locations);
+
+        Ok(resp
+            .result
+            .into_iter()
+            .map(|launch| EnrichedLaunch {
+                provider: launch
+                    .provider
+                    .id
+                    .and_then(|id| providers.get(&id).cloned()),
+                vehicle: launch.vehicle.id.and_then(|id| vehicles.get(&id).cloned()),
+                location: launch
+                    .pad
+                    .location
+                    .id
+                    .and_then(|id| locations.get(&id).cloned()),
+                launch,
+            })
+            .collect())
+    }
+
+    /// Stream the pages of launches in the database (optionally filtered by params).
+    ///
+    /// Each item is a whole response, with its pagination fields intact. The stream starts at the
+    /// page set in params (or the first page) and ends after the last page.
+    pub fn launches_pages_stream(
+        &self,
+        params: Option<Params>,
+    ) -> impl Stream<Item = Result<Response<Launch>, RllError>> + '_ {
+        self.pages("launches", params)
+    }
+
+    /// Call f with each launch in the database (optionally filtered by params), page by page.
+    ///
+    /// Each call is awaited before moving on to the next launch. The first error returned by f or
+    /// by the API stops the iteration and is returned.
+    pub async fn for_each_launch<F, Fut, E>(&self, params: Option<Params>, f: F) -> Result<(), E>
+    where
+        F: FnMut(Launch) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: From<RllError>,
+    {
+        self.for_each_launch_concurrent(params, 1, f).await
+    }
+
+    /// Call f with each launch in the database (optionally filtered by params), page by page.
+    ///
+    /// Up to concurrency calls to f run at the same time. The next page is only requested after
+    /// all the calls for the current page are done.
+    pub async fn for_each_launch_concurrent<F, Fut, E>(
+        &self,
+        params: Option<Params>,
+        concurrency: usize,
+        mut f: F,
+    ) -> Result<(), E>
+    where
+        F: FnMut(Launch) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: From<RllError>,
+    {
+        let mut pages = pin!(self.pages::<Launch>("launches", params));
+
+        while let Some(resp) = pages.try_next().await? {
+            futures::stream::iter(resp.result.into_iter().map(&mut f))
+                .buffer_unordered(concurrency.max(1))
+                .try_collect::<()>()
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve all launches modified between since and until (optionally filtered by params).
+    ///
+    /// The API only supports a lower bound, so since is sent as the modified_since parameter and
+    /// the launches modified after until are filtered out of the results by the client. Launches
+    /// with an unparseable modified field are left out too.
+    pub async fn launches_modified_between(
+        &self,
+        since: NaiveDateTime,
+        until: NaiveDateTime,
+        params: Option<Params>,
+    ) -> Result<Vec<Launch>, RllError> {
+        let modified_since = LaunchParamsBuilder::new().modified_since_dt(since).build();
+        let params = params.unwrap_or_default().merge(modified_since);
+        let launches: Vec<Launch> = self.launches_all(Some(params)).await?;
+        let until = until.and_utc();
+
+        Ok(launches
+            .into_iter()
+            .filter(|launch| {
+                api_models::parse_datetime(&launch.modified)
+                    .is_some_and(|modified| modified <= until)
+            })
+            .collect())
+    }
+
+    /// Retrieve all orbital launches in the database (optionally filtered by params) or an error.
+    ///
+    /// The API has no suborbital filter, so all the pages of launches are fetched and the
+    /// suborbital launches are filtered out of the results by the client.
+    pub async fn launches_orbital_only(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<Launch>, RllError> {
+        let launches: Vec<Launch> = self.launches_all(params).await?;
+
+        Ok(launches
+            .into_iter()
+            .filter(|launch| !launch.suborbital)
+            .collect())
+    }
+
+    /// Retrieve all launches between two dates (optionally filtered by extra), sorted by date.
+    ///
+    /// The dates are sent as the after_date and before_date parameters, replacing any set in
+    /// extra. A start later than the end is an error, before any request is sent.
+    pub async fn launches_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        extra: Option<Params>,
+    ) -> Result<Vec<Launch>, RllError> {
+        let dates = LaunchParamsBuilder::new()
+            .after_date_deferred(Some(start))
+            .before_date_deferred(Some(end))
+            .try_build()?;
+        let params = extra.unwrap_or_default().merge(dates);
+        let mut launches: Vec<Launch> = self.launches_all(Some(params)).await?;
+
+        launches.sort_by_key(Launch::sort_key);
+
+        Ok(launches)
+    }
+
+    /// Retrieve the launches in a country from now until now + within, soonest first, or an error.
+    ///
+    /// All the pages of launches are fetched. The API filters by whole dates, so launches whose
+    /// sort date falls outside the exact window are filtered out by the client. With weather_only,
+    /// only launches with a weather forecast are kept.
+    pub async fn launches_upcoming_window(
+        &self,
+        country_code: &str,
+        within: chrono::Duration,
+        weather_only: bool,
+    ) -> Result<Vec<Launch>, RllError> {
+        let now = Utc::now();
+        let until = now + within;
+        let params = LaunchParamsBuilder::new()
+            .country_code(country_code)
+            .after_date_deferred(Some(now.date_naive()))
+            .before_date_deferred(Some(until.date_naive() + chrono::Duration::days(1)))
+            .direction(Direction::Ascending)
+            .build();
+        let launches: Vec<Launch> = self.request_all("launches", Some(params)).await?;
+
+        Ok(launches
+            .into_iter()
+            .filter(|launch| (now..=until).contains(&launch.sort_key()))
+            .filter(|launch| !weather_only || launch.weather().is_some())
+            .collect())
+    }
+
+    /// Retrieve the soonest launch from today, of any provider or only of one, or an error.
+    ///
+    /// None is returned when no launch is scheduled.
+    pub async fn next_launch(&self, provider_id: Option<i64>) -> Result<Option<Launch>, RllError> {
+        let mut builder = LaunchParamsBuilder::new();
+        builder
+            .after_date_deferred(Some(Utc::now().date_naive()))
+            .direction(Direction::Ascending)
+            .limit(1);
+
+        if let Some(provider_id) = provider_id {
+            builder.provider_id(provider_id);
+        }
+
+        let resp: Response<Launch> = self.request("launches", Some(builder.build())).await?;
+
+        Ok(resp.result.into_iter().next())
+    }
+
+    /// Retrieve the upcoming launches (optionally filtered by params) as an iCalendar, or an error.
+    ///
+    /// All the pages of launches are fetched. Unless params set an after_date, only launches from
+    /// today onwards are included. The calendar is rendered by [`launches_to_ics`].
+    #[cfg(feature = "ics")]
+    pub async fn launches_ics(&self, params: Option<Params>) -> Result<String, RllError> {
+        let params = params.unwrap_or_default();
+        let params = if params.contains("after_date") {
+            params
+        } else {
+            LaunchParamsBuilder::new()
+                .after_date_deferred(Some(Utc::now().date_naive()))
+                .build()
+                .merge(params)
+        };
+        let launches: Vec<Launch> = self.launches_all(Some(params)).await?;
+
+        Ok(launches_to_ics(&launches))
+    }
+
+    /// Retrieve the launches at a pad between two dates, sorted by window open, or an error.
+    ///
+    /// Launches without a known window are placed by their T-0 or, failing that, their sort date.
+    pub async fn pad_schedule(
+        &self,
+        pad_id: i64,
+        after: NaiveDate,
+        before: NaiveDate,
+    ) -> Result<Vec<Launch>, RllError> {
+        let params = LaunchParamsBuilder::new()
+            .pad_id(pad_id)
+            .after_date_deferred(Some(after))
+            .before_date_deferred(Some(before))
+            .build();
+        let mut launches: Vec<Launch> = self.request_all("launches", Some(params)).await?;
+
+        launches.sort_by_key(|launch| {
+            launch
+                .window()
+                .and_then(|window| window.open.or(window.t0))
+                .unwrap_or_else(|| launch.sort_key())
+        });
+
+        Ok(launches)
+    }
+
+    /// Retrieve all locations in the database (optionally filtered by params) or an error.
+    pub async fn locations<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Response<T>, RllError> {
+        self.request("locations", params).await
+    }
+
+    /// Retrieve every page of locations (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn locations_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("locations", Some(params)).await
+    }
+
+    /// Retrieve the location with the given id, None if there's no such location, or an error.
+    pub async fn location(&self, id: i64) -> Result<Option<Location>, RllError> {
+        self.request_by_id("locations", id).await
+    }
+
+    /// Retrieve the location with the given slug, None if there's no such location, or an error.
+    ///
+    /// The API has no slug filter for locations, so the pages of locations are searched by the
+    /// client, until the location is found or there are no more pages. Without a name hint this is
+    /// a full scan of the endpoint, one request per page. A name hint, such as the slug with its
+    /// dashes turned into spaces, filters the locations on the server to narrow the scan.
+    pub async fn location_by_slug(
+        &self,
+        slug: &str,
+        name: Option<&str>,
+    ) -> Result<Option<Location>, RllError> {
+        let params = name.map(|name| LocationParamsBuilder::new().name(name).build());
+        let locations = pin!(self.paginate::<Location>("locations", params));
+
+        locations
+            .try_filter(|location| futures::future::ready(location.slug == slug))
+            .try_next()
+            .await
+    }
+
+    /// Count the locations in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_locations(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("locations", params).await
+    }
+
+    /// Retrieve all missions in the database (optionally filtered by params) or an error.
     pub async fn missions<T: DeserializeOwned>(
         &self,
         params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
+    ) -> Result<Response<T>, RllError> {
         self.request("missions", params).await
     }
 
+    /// Retrieve every page of missions (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn missions_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("missions", Some(params)).await
+    }
+
+    /// Retrieve the mission with the given id, None if there's no such mission, or an error.
+    pub async fn mission(&self, id: i64) -> Result<Option<Mission>, RllError> {
+        self.request_by_id("missions", id).await
+    }
+
+    /// Count the missions in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_missions(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("missions", params).await
+    }
+
     /// Retrieve all pads in the database (optionally filtered by params) or an error.
     pub async fn pads<T: DeserializeOwned>(
         &self,
         params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
+    ) -> Result<Response<T>, RllError> {
         self.request("pads", params).await
     }
 
+    /// Retrieve every page of pads (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn pads_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("pads", Some(params)).await
+    }
+
+    /// Retrieve the pad with the given id, None if there's no such pad, or an error.
+    pub async fn pad(&self, id: i64) -> Result<Option<Pad>, RllError> {
+        self.request_by_id("pads", id).await
+    }
+
+    /// Count the pads in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_pads(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("pads", params).await
+    }
+
     /// Retrieve all tags in the database (optionally filtered by params) or an error.
     pub async fn tags<T: DeserializeOwned>(
         &self,
         params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
+    ) -> Result<Response<T>, RllError> {
         self.request("tags", params).await
     }
 
+    /// Retrieve every page of tags (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn tags_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("tags", Some(params)).await
+    }
+
+    /// Retrieve the tag with the given id, None if there's no such tag, or an error.
+    pub async fn tag(&self, id: i64) -> Result<Option<Tag>, RllError> {
+        self.request_by_id("tags", id).await
+    }
+
+    /// Count the tags in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_tags(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("tags", params).await
+    }
+
+    /// Retrieve the full records of the tags used by the launches, mapped by id, or an error.
+    ///
+    /// Each distinct tag id is fetched only once, no matter how many launches share it.
+    pub async fn resolve_tags(&self, launches: &[Launch]) -> Result<HashMap<i64, Tag>, RllError> {
+        let ids: BTreeSet<i64> = launches
+            .iter()
+            .flat_map(|launch| &launch.tags)
+            .filter_map(|tag| tag.id)
+            .collect();
+        let tags: Vec<Tag> = self.request_by_ids("tags", ids).await?;
+
+        Ok(api_models::index_by_id(tags))
+    }
+
     /// Retrieve all vehicles in the database (optionally filtered by params) or an error.
     pub async fn vehicles<T: DeserializeOwned>(
         &self,
         params: Option<Params>,
-    ) -> Result<Response<T>, Box<dyn Error>> {
+    ) -> Result<Response<T>, RllError> {
         self.request("vehicles", params).await
     }
+
+    /// Retrieve every page of vehicles (optionally filtered by params) as a single list, or an error.
+    ///
+    /// The pages are requested one after the other from the first page, whatever page params set,
+    /// until the last page or an empty page. Any other parameter, such as the limit, is kept.
+    pub async fn vehicles_all<T: DeserializeOwned>(
+        &self,
+        params: Option<Params>,
+    ) -> Result<Vec<T>, RllError> {
+        let params = params.unwrap_or_default().with_page(1);
+
+        self.request_all("vehicles", Some(params)).await
+    }
+
+    /// Retrieve the vehicle with the given id, None if there's no such vehicle, or an error.
+    pub async fn vehicle(&self, id: i64) -> Result<Option<Vehicle>, RllError> {
+        self.request_by_id("vehicles", id).await
+    }
+
+    /// Retrieve the vehicle with the given slug, None if there's no such vehicle, or an error.
+    ///
+    /// The API has no slug filter for vehicles, so the pages of vehicles are searched by the
+    /// client, until the vehicle is found or there are no more pages. Without a name hint this is
+    /// a full scan of the endpoint, one request per page. A name hint, such as the slug with its
+    /// dashes turned into spaces, filters the vehicles on the server to narrow the scan.
+    pub async fn vehicle_by_slug(
+        &self,
+        slug: &str,
+        name: Option<&str>,
+    ) -> Result<Option<Vehicle>, RllError> {
+        let params = name.map(|name| VehicleParamsBuilder::new().name(name).build());
+        let vehicles = pin!(self.paginate::<Vehicle>("vehicles", params));
+
+        vehicles
+            .try_filter(|vehicle| futures::future::ready(vehicle.slug == slug))
+            .try_next()
+            .await
+    }
+
+    /// Count the vehicles in the database (optionally filtered by params), or an error.
+    ///
+    /// A single record is requested to read the total, which is an error if it's missing.
+    pub async fn count_vehicles(&self, params: Option<Params>) -> Result<i64, RllError> {
+        self.request_count("vehicles", params).await
+    }
+
+    /// Retrieve the n vehicles with the most launches, with their launch count, or an error.
+    ///
+    /// This lists every vehicle and then counts the launches of each one, so it sends one request
+    /// per page of vehicles plus one per vehicle, a few at a time.
+    pub async fn top_vehicles_by_launches(
+        &self,
+        n: usize,
+    ) -> Result<Vec<(Vehicle, i64)>, RllError> {
+        let vehicles: Vec<Vehicle> = self.request_all("vehicles", None).await?;
+        let mut ranking: Vec<(Vehicle, i64)> = futures::stream::iter(vehicles)
+            .filter_map(|vehicle| async move { vehicle.id.map(|id| (vehicle, id)) })
+            .map(|(vehicle, id)| async move {
+                let params = LaunchParamsBuilder::new().vehicle_id(id).build();
+                let count = self.request_count("launches", Some(params)).await?;
+
+                Ok::<_, RllError>((vehicle, count))
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+
+        ranking.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        ranking.truncate(n);
+
+        Ok(ranking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    pub(crate) const COMPANY: &str = include_str!("../tests/fixtures/company.json");
+    pub(crate) const LAUNCH: &str = include_str!("../tests/fixtures/launch.json");
+    const LOCATION: &str = include_str!("../tests/fixtures/location.json");
+    const MISSION: &str = include_str!("../tests/fixtures/mission.json");
+    const PAD: &str = include_str!("../tests/fixtures/pad.json");
+    const TAG: &str = include_str!("../tests/fixtures/tag.json");
+    const VEHICLE: &str = include_str!("../tests/fixtures/vehicle.json");
+
+    /// Response sent by the mock server: status, extra headers, body and delay before sending.
+    #[derive(Clone)]
+    pub(crate) struct MockResponse {
+        status: u16,
+        headers: Vec<(&'static str, &'static str)>,
+        body: &'static str,
+        delay: Duration,
+    }
+
+    impl MockResponse {
+        pub(crate) fn ok(body: &'static str) -> Self {
+            Self {
+                status: 200,
+                headers: Vec::new(),
+                body,
+                delay: Duration::from_millis(10),
+            }
+        }
+
+        pub(crate) fn status(status: u16) -> Self {
+            Self {
+                status,
+                ..Self::ok("")
+            }
+        }
+
+        pub(crate) fn header(mut self, name: &'static str, value: &'static str) -> Self {
+            self.headers.push((name, value));
+
+            self
+        }
+
+        pub(crate) fn delay(mut self, delay: Duration) -> Self {
+            self.delay = delay;
+
+            self
+        }
+    }
+
+    /// Local server answering the requests with scripted responses, one request per connection.
+    ///
+    /// The n-th request gets the n-th response, and the requests after the last response get the
+    /// last response again.
+    pub(crate) struct MockServer {
+        url: String,
+        /// Head of every request received, request line and headers.
+        requests: Arc<Mutex<Vec<String>>>,
+        /// Largest number of requests handled at the same time.
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    impl MockServer {
+        /// Start a server answering every request with the same body.
+        pub(crate) async fn start(body: &'static str) -> Self {
+            Self::scripted(vec![MockResponse::ok(body)]).await
+        }
+
+        pub(crate) async fn scripted(responses: Vec<MockResponse>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            let max_in_flight = Arc::new(AtomicUsize::new(0));
+            let responses = Arc::new(responses);
+            let server = MockServer {
+                url,
+                requests: requests.clone(),
+                max_in_flight: max_in_flight.clone(),
+            };
+
+            tokio::spawn(async move {
+                for n in 0.. {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let requests = requests.clone();
+                    let in_flight = in_flight.clone();
+                    let max_in_flight = max_in_flight.clone();
+                    let response = responses[n.min(responses.len() - 1)].clone();
+
+                    tokio::spawn(async move {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                        let mut head = Vec::new();
+                        let mut buffer = [0; 1024];
+
+                        while !head.ends_with(b"\r\n\r\n") {
+                            match socket.read(&mut buffer).await {
+                                Ok(0) | Err(_) => break,
+                                Ok(read) => head.extend_from_slice(&buffer[..read]),
+                            }
+                        }
+
+                        requests
+                            .lock()
+                            .unwrap()
+                            .push(String::from_utf8_lossy(&head).to_string());
+                        tokio::time::sleep(response.delay).await;
+
+                        let status = reqwest::StatusCode::from_u16(response.status).unwrap();
+                        let headers: String = response
+                            .headers
+                            .iter()
+                            .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                            .collect();
+                        let response = format!(
+                            "HTTP/1.1 {}\r\nContent-Type: application/json\r\n{}\
+                             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            status,
+                            headers,
+                            response.body.len(),
+                            response.body
+                        );
+                        let _ = socket.write_all(response.as_bytes()).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    });
+                }
+            });
+
+            server
+        }
+
+        pub(crate) fn client(&self) -> RocketLaunchLive {
+            RocketLaunchLive::with_base_url("key", &self.url).unwrap()
+        }
+
+        pub(crate) fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[test]
+    fn fixtures_parse() {
+        fn first<T: DeserializeOwned>(body: &str) -> T {
+            parse_response::<T>(body)
+                .unwrap()
+                .result
+                .into_iter()
+                .next()
+                .unwrap()
+        }
+
+        assert_eq!(first::<Company>(COMPANY).country.code, "US");
+        assert_eq!(
+            first::<Launch>(LAUNCH).pad.location.state.as_deref(),
+            Some("FL")
+        );
+        assert_eq!(first::<Location>(LOCATION).slug, "cape-canaveral-sfs");
+        assert_eq!(first::<Mission>(MISSION).name, "Test Flight");
+        assert_eq!(first::<Pad>(PAD).name, "SLC-40");
+        assert_eq!(first::<Tag>(TAG).text, "Test Flight");
+        assert_eq!(first::<Vehicle>(VEHICLE).company_id, Some(1));
+    }
+
+    #[tokio::test]
+    async fn mock_server_serves_fixture() {
+        let server = MockServer::start(VEHICLE).await;
+        let resp: Response<Vehicle> = server.client().vehicles(None).await.unwrap();
+
+        assert_eq!(resp.result[0].name, "Falcon 9");
+        assert!(server.requests()[0].starts_with("GET /json/vehicles? HTTP/1.1"));
+    }
+
+    fn api_error(body: &str) -> Option<String> {
+        match parse_response::<Launch>(body) {
+            Err(RllError::Api(message)) => Some(message),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parse_response_errors_array() {
+        let body = r#"{"errors": ["Invalid key", "Try again"], "valid_auth": false, "result": []}"#;
+
+        assert_eq!(api_error(body).as_deref(), Some("Invalid key Try again"));
+    }
+
+    #[test]
+    fn parse_response_error_field() {
+        assert_eq!(
+            api_error(r#"{"error": "Rate limited"}"#).as_deref(),
+            Some("Rate limited")
+        );
+    }
+
+    #[test]
+    fn parse_response_message_field() {
+        assert_eq!(
+            api_error(r#"{"message": "Not found"}"#).as_deref(),
+            Some("Not found")
+        );
+    }
+
+    #[test]
+    fn parse_response_empty_result() {
+        let body = r#"{"errors": null, "valid_auth": true, "count": 0, "total": 0, "result": []}"#;
+        let resp = parse_response::<Launch>(body).unwrap();
+
+        assert!(resp.result.is_empty());
+        assert_eq!(resp.total, Some(0));
+    }
+
+    #[tokio::test]
+    async fn accept_language_header() {
+        let server = MockServer::start(TAG).await;
+        let has_header = |request: &String| {
+            request
+                .lines()
+                .any(|line| line.to_ascii_lowercase().starts_with("accept-language:"))
+        };
+
+        server.client().tags::<Tag>(None).await.unwrap();
+        server
+            .client()
+            .accept_language("pt-PT")
+            .tags::<Tag>(None)
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(!has_header(&requests[0]));
+        assert!(has_header(&requests[1]));
+        assert!(requests[1]
+            .to_ascii_lowercase()
+            .contains("accept-language: pt-pt\r\n"));
+    }
+
+    #[tokio::test]
+    async fn batched_requests() {
+        let server = MockServer::start(LAUNCH).await;
+        let ids: Vec<i64> = (1..=120).collect();
+        let launches = server
+            .client()
+            .batching(50, 4)
+            .launches_by_ids(&ids)
+            .await
+            .unwrap();
+
+        assert_eq!(launches.len(), 120);
+        assert_eq!(server.requests().len(), 120);
+        assert!(server.max_in_flight.load(Ordering::SeqCst) <= 4);
+    }
+
+    #[tokio::test]
+    async fn launches_by_tags_merges() {
+        let server = MockServer::start(LAUNCH).await;
+        let launches = server
+            .client()
+            .launches_by_tags(&[1, 7], None)
+            .await
+            .unwrap();
+        let requests = server.requests();
+
+        assert_eq!(launches.len(), 1);
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("tag_id=1&"));
+        assert!(requests[1].contains("tag_id=7&"));
+    }
+
+    #[tokio::test]
+    async fn launches_enriched_cap() {
+        let server = MockServer::start(LAUNCH).await;
+        let client = server.client();
+
+        assert!(matches!(
+            client.launches_enriched(None, 2).await,
+            Err(RllError::TooManyLookups { needed: 3, max: 2 })
+        ));
+        assert_eq!(server.requests().len(), 1);
+
+        let launches = client.launches_enriched(None, 3).await.unwrap();
+
+        assert_eq!(launches.len(), 1);
+        assert_eq!(server.requests().len(), 5);
+    }
+
+    #[test]
+    fn deferred_error_cleared_by_valid_date() {
+        let date = NaiveDate::from_ymd_opt(2023, 9, 1);
+        let mut builder = LaunchParamsBuilder::new();
+        builder.after_date(date).unwrap().after_date_deferred(None);
+
+        assert!(builder.try_build().is_err());
+        assert_eq!(builder.build().as_query_string(), "");
+
+        builder.after_date(date).unwrap();
+
+        assert_eq!(
+            builder.try_build().unwrap().as_query_string(),
+            "after_date=2023-09-01"
+        );
+    }
+
+    #[tokio::test]
+    async fn launches_between_starts_at_first_page() {
+        let server = MockServer::start(LAUNCH).await;
+        let start = NaiveDate::from_ymd_opt(2023, 9, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
+        let extra = LaunchParamsBuilder::new().page(3).build();
+        let launches = server
+            .client()
+            .launches_between(start, end, Some(extra))
+            .await
+            .unwrap();
+
+        assert_eq!(launches.len(), 1);
+        assert!(server.requests()[0].contains("page=1 "));
+    }
+
+    #[tokio::test]
+    async fn transient_error_is_retried() {
+        let server =
+            MockServer::scripted(vec![MockResponse::status(503), MockResponse::ok(LAUNCH)]).await;
+        let client = server.client().with_retries(2, Duration::from_millis(1));
+        let resp: Response<Launch> = client.launches(None).await.unwrap();
+
+        assert_eq!(resp.result.len(), 1);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_exhausted() {
+        let server = MockServer::scripted(vec![MockResponse::status(503)]).await;
+        let client = server.client().with_retries(2, Duration::from_millis(1));
+        let err = client.launches::<Launch>(None).await.unwrap_err();
+
+        assert!(matches!(
+            &err,
+            RllError::Retries { attempts: 3, error } if matches!(**error, RllError::Http(_))
+        ));
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn stalled_server_times_out() {
+        let response = MockResponse::ok(LAUNCH).delay(Duration::from_secs(5));
+        let server = MockServer::scripted(vec![response]).await;
+        let client = server
+            .client()
+            .with_timeout(Duration::from_millis(50))
+            .unwrap();
+
+        assert!(matches!(
+            client.launches::<Launch>(None).await,
+            Err(RllError::Timeout(_))
+        ));
+    }
 }
@@ -1,9 +1,106 @@
-/// Simplify conditional concatenation of API parameters.
+/// Simplify conditional concatenation of API parameters, percent-encoding their values.
 #[macro_export]
 macro_rules! add_param {
     ($vec:expr, $val:expr, $name:expr) => {
         if let Some(value) = $val {
-            $vec.push(format!("{}={}", $name, value));
+            $vec.push($crate::param($name, value));
         }
     };
 }
+
+/// Generate the setters of the common parameters of a builder, for the entity it filters.
+///
+/// Setting the country_code parameter also generates the country setter, which sets it from a
+/// known country code.
+macro_rules! common_setters {
+    ($lt:lifetime, $entity:literal, $($setter:ident),+) => {
+        $($crate::macros::common_setters!(@ $lt, $entity, $setter);)+
+    };
+    (@ $lt:lifetime, $entity:literal, id) => {
+        #[doc = concat!("Set the ", $entity, " id parameter.")]
+        ///
+        #[doc = concat!(
+            "The id matches a single ",
+            $entity,
+            ", so the API ignores any other filter set alongside it."
+        )]
+        pub fn id(&mut self, id: i64) -> &mut Self {
+            self.common_params.id = Some(id);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, name) => {
+        #[doc = concat!("Set the ", $entity, " name parameter.")]
+        pub fn name(&mut self, name: &$lt str) -> &mut Self {
+            self.common_params.name = Some(name);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, state_abbr) => {
+        #[doc = concat!("Set the ", $entity, " state_abbr parameter.")]
+        pub fn state_abbr(&mut self, state_abbr: &$lt str) -> &mut Self {
+            self.common_params.state_abbr = Some(state_abbr);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, country_code) => {
+        #[doc = concat!("Set the ", $entity, " country_code parameter.")]
+        pub fn country_code(&mut self, country_code: &$lt str) -> &mut Self {
+            self.common_params.country_code = Some(country_code);
+
+            self
+        }
+
+        #[doc = concat!("Set the ", $entity, " country_code parameter from a known country code.")]
+        pub fn country(&mut self, country: CountryCode) -> &mut Self {
+            self.common_params.country_code = Some(country.code());
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, slug) => {
+        #[doc = concat!("Set the ", $entity, " slug parameter.")]
+        pub fn slug(&mut self, slug: &$lt str) -> &mut Self {
+            self.common_params.slug = Some(slug);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, limit) => {
+        #[doc = concat!("Set the ", $entity, " limit parameter, the number of records per page.")]
+        ///
+        /// An endpoint that doesn't support a limit ignores it, and returns its default page size.
+        pub fn limit(&mut self, limit: i64) -> &mut Self {
+            self.common_params.limit = Some(limit);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, direction) => {
+        #[doc = concat!(
+            "Set the ",
+            $entity,
+            " direction parameter, the sorting order of the results."
+        )]
+        ///
+        /// An endpoint that doesn't support sorting ignores it, and returns its default order.
+        pub fn direction(&mut self, direction: Direction) -> &mut Self {
+            self.common_params.direction = Some(direction);
+
+            self
+        }
+    };
+    (@ $lt:lifetime, $entity:literal, page) => {
+        #[doc = concat!("Set the ", $entity, " page parameter.")]
+        pub fn page(&mut self, page: i64) -> &mut Self {
+            self.common_params.page = Some(page);
+
+            self
+        }
+    };
+}
+
+pub(crate) use common_setters;
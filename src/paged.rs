@@ -0,0 +1,130 @@
+//! Page by page fetching of an endpoint, prefetching the next page in the background.
+use crate::api_models::Response;
+use crate::{Params, RllError, RocketLaunchLive};
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+/// Fetcher of the pages of an endpoint, one at a time, with the next page prefetched.
+///
+/// Each call to [`Self::next`] moves to the next page, starting at the page set in params (or the
+/// first page), and starts requesting the page after it in a background task, so that it's ready
+/// by the time it's needed. The fetcher stops after the last page, or after an empty page.
+///
+/// ```no_run
+/// use rocket_launch_live::api_models::Launch;
+/// use rocket_launch_live::RocketLaunchLive;
+///
+/// # async fn run() -> Result<(), rocket_launch_live::RllError> {
+/// let client = RocketLaunchLive::new("key");
+/// let mut pages = client.paged::<Launch>("launches", None);
+///
+/// while let Some(page) = pages.next().await? {
+///     println!("{} launches", page.result.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PagedFetcher<T> {
+    client: RocketLaunchLive,
+    endpoint: String,
+    params: Params,
+    page: i64,
+    current: Option<Response<T>>,
+    prefetch: Option<JoinHandle<Result<Response<T>, RllError>>>,
+    done: bool,
+}
+
+impl<T: DeserializeOwned + Send + 'static> PagedFetcher<T> {
+    /// Create a fetcher of the pages of an endpoint (optionally filtered by params).
+    pub fn new(client: RocketLaunchLive, endpoint: &str, params: Option<Params>) -> Self {
+        let params = params.unwrap_or_default();
+
+        Self {
+            client,
+            endpoint: endpoint.to_string(),
+            page: params.page().unwrap_or(1) - 1,
+            params,
+            current: None,
+            prefetch: None,
+            done: false,
+        }
+    }
+
+    /// Get the page fetched by the last call to [`Self::next`], if any.
+    pub fn current(&self) -> Option<&Response<T>> {
+        self.current.as_ref()
+    }
+
+    /// Get the number of the current page, or the page before the first one if none was fetched.
+    pub fn page(&self) -> i64 {
+        self.page
+    }
+
+    /// Move to the next page, None after the last page, or an error.
+    ///
+    /// An error ends the fetching, so any later call returns None.
+    pub async fn next(&mut self) -> Result<Option<&Response<T>>, RllError> {
+        if self.done {
+            self.current = None;
+
+            return Ok(None);
+        }
+
+        let page = self.page + 1;
+        let result = match self.prefetch.take() {
+            Some(prefetch) => prefetch
+                .await
+                .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())),
+            None => self.fetch(page).await,
+        };
+        let resp = match result {
+            Ok(resp) if !resp.result.is_empty() => resp,
+            Ok(_) => {
+                self.finish();
+
+                return Ok(None);
+            }
+            Err(e) => {
+                self.finish();
+
+                return Err(e);
+            }
+        };
+
+        if resp.has_next_page(page) {
+            let client = self.client.clone();
+            let endpoint = self.endpoint.clone();
+            let params = self.params.with_page(page + 1);
+
+            self.prefetch = Some(tokio::spawn(async move {
+                client.get(&endpoint, Some(params)).await
+            }));
+        } else {
+            self.done = true;
+        }
+
+        self.page = page;
+        self.current = Some(resp);
+
+        Ok(self.current.as_ref())
+    }
+
+    async fn fetch(&self, page: i64) -> Result<Response<T>, RllError> {
+        self.client
+            .get(&self.endpoint, Some(self.params.with_page(page)))
+            .await
+    }
+
+    fn finish(&mut self) {
+        self.done = true;
+        self.current = None;
+    }
+}
+
+impl<T> Drop for PagedFetcher<T> {
+    fn drop(&mut self) {
+        if let Some(prefetch) = &self.prefetch {
+            prefetch.abort();
+        }
+    }
+}
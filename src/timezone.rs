@@ -0,0 +1,69 @@
+//! Lookup of the timezone of a launch site.
+use crate::api_models::Location;
+use chrono_tz::Tz;
+
+/// Timezones of the US states with launch sites, by state abbreviation.
+const US_STATES: [(&str, Tz); 10] = [
+    ("AK", Tz::America__Anchorage),
+    ("CA", Tz::America__Los_Angeles),
+    ("FL", Tz::America__New_York),
+    ("GA", Tz::America__New_York),
+    ("HI", Tz::Pacific__Honolulu),
+    ("MD", Tz::America__New_York),
+    ("NM", Tz::America__Denver),
+    ("TX", Tz::America__Chicago),
+    ("UT", Tz::America__Denver),
+    ("VA", Tz::America__New_York),
+];
+
+/// Timezones of the countries with launch sites that span a single timezone, by country name.
+const COUNTRIES: [(&str, Tz); 16] = [
+    ("Brazil", Tz::America__Fortaleza),
+    ("China", Tz::Asia__Shanghai),
+    ("French Guiana", Tz::America__Cayenne),
+    ("India", Tz::Asia__Kolkata),
+    ("Iran", Tz::Asia__Tehran),
+    ("Israel", Tz::Asia__Jerusalem),
+    ("Japan", Tz::Asia__Tokyo),
+    ("Kazakhstan", Tz::Asia__Qyzylorda),
+    ("Marshall Islands", Tz::Pacific__Kwajalein),
+    ("New Zealand", Tz::Pacific__Auckland),
+    ("North Korea", Tz::Asia__Pyongyang),
+    ("Norway", Tz::Europe__Oslo),
+    ("Portugal", Tz::Atlantic__Azores),
+    ("South Korea", Tz::Asia__Seoul),
+    ("Sweden", Tz::Europe__Stockholm),
+    ("United Kingdom", Tz::Europe__London),
+];
+
+/// Timezones of launch sites in countries that span several timezones, by location name.
+const SITES: [(&str, Tz); 4] = [
+    ("Plesetsk", Tz::Europe__Moscow),
+    ("Kapustin Yar", Tz::Europe__Volgograd),
+    ("Vostochny", Tz::Asia__Yakutsk),
+    ("Yasny", Tz::Asia__Yekaterinburg),
+];
+
+/// Find the timezone of a location from its name, state or country, if known.
+pub(crate) fn site_timezone(location: &Location) -> Option<Tz> {
+    let by_site = SITES
+        .iter()
+        .find(|(site, _)| location.name.contains(site))
+        .map(|(_, tz)| *tz);
+    let by_state = || {
+        let state = location.state.as_deref()?;
+
+        US_STATES
+            .iter()
+            .find(|(abbr, _)| abbr.eq_ignore_ascii_case(state))
+            .map(|(_, tz)| *tz)
+    };
+    let by_country = || {
+        COUNTRIES
+            .iter()
+            .find(|(country, _)| country.eq_ignore_ascii_case(location.country.trim()))
+            .map(|(_, tz)| *tz)
+    };
+
+    by_site.or_else(by_state).or_else(by_country)
+}
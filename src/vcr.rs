@@ -0,0 +1,57 @@
+//! Recording and replay of API responses for offline tests.
+use crate::RllError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Cassette file holding the recorded response bodies, keyed by request method and URL.
+#[derive(Debug)]
+pub(crate) struct Cassette {
+    path: PathBuf,
+    interactions: Mutex<BTreeMap<String, String>>,
+}
+
+impl Cassette {
+    /// Open a cassette, loading the interactions already recorded in the file, if any.
+    pub(crate) fn open(path: PathBuf) -> Result<Self, RllError> {
+        let interactions = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path,
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// Get the recorded response body of a request.
+    pub(crate) fn replay(&self, request: &str) -> Option<String> {
+        self.interactions.lock().ok()?.get(request).cloned()
+    }
+
+    /// Record the response body of a request and save the cassette file.
+    ///
+    /// Any occurrence of the API key is redacted before it's written.
+    pub(crate) fn record(&self, request: &str, body: &str, key: &str) -> Result<(), RllError> {
+        let redact = |text: &str| {
+            if key.is_empty() {
+                text.to_string()
+            } else {
+                text.replace(key, "REDACTED")
+            }
+        };
+        let mut interactions = self
+            .interactions
+            .lock()
+            .map_err(|_| io::Error::other("The cassette lock is poisoned."))?;
+
+        interactions.insert(redact(request), redact(body));
+        fs::write(&self.path, serde_json::to_string_pretty(&*interactions)?)?;
+
+        Ok(())
+    }
+}